@@ -0,0 +1,88 @@
+/// Barrett reduction for repeated reductions by one fixed modulus. A single
+/// precomputed quotient `floor(10^(2k) / m)` turns each reduction into two
+/// multiplications and digit shifts, and unlike Montgomery it works for any
+/// positive modulus, including even ones.
+use crate::{div_euclid, multiply, shift_right, LargeInt};
+use std::cmp::Ordering;
+
+pub struct BarrettCtx {
+    modulus: LargeInt,
+    /// Number of decimal digits in the modulus.
+    k: usize,
+    /// floor(10^(2k) / modulus).
+    mu: LargeInt,
+}
+
+impl BarrettCtx {
+    /// Prepares a context for the given modulus.
+    /// Panics if the modulus is not positive.
+    pub fn new(modulus: &LargeInt) -> Self {
+        if modulus.sign == -1 || modulus.is_zero() {
+            panic!("Barrett modulus must be positive!");
+        }
+
+        let k = modulus.num_digits();
+        BarrettCtx {
+            modulus: modulus.clone(),
+            k,
+            mu: div_euclid(&LargeInt::pow10(2 * k), modulus).0,
+        }
+    }
+
+    /// Returns `x` reduced into `0..modulus`, matching `div_euclid(x, modulus).1`.
+    /// Non-negative inputs below `10^(2k)`, such as products of two reduced
+    /// values, take the fast path; anything else falls back to a full division.
+    pub fn reduce(&self, x: &LargeInt) -> LargeInt {
+        if x.sign == -1 || x.num_digits() > 2 * self.k {
+            return div_euclid(x, &self.modulus).1;
+        }
+
+        let estimate = shift_right(&multiply(&shift_right(x, self.k - 1), &self.mu), self.k + 1);
+        // The estimated quotient is at most two below the true one.
+        let mut remainder = x.subtract(&multiply(&estimate, &self.modulus));
+        while remainder.compare_abs(&self.modulus) != Ordering::Less {
+            remainder = remainder.sub_if_ge(&self.modulus);
+        }
+        remainder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reduce_matches_div_euclid() {
+        let mut seed: u64 = 17;
+        let mut next = || {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            seed >> 11
+        };
+
+        for m in ["1", "2", "10", "97", "1000000", "18446744073709551616", "99999999999999999999999"] {
+            let modulus = LargeInt::parse(m);
+            let ctx = BarrettCtx::new(&modulus);
+            for _ in 0..50 {
+                let x = multiply(&LargeInt::from(next() as i64), &LargeInt::from(next() as i64));
+                assert_eq!(ctx.reduce(&x).to_string(), div_euclid(&x, &modulus).1.to_string(), "{} mod {}", x, m);
+                let square = x.square();
+                assert_eq!(ctx.reduce(&square).to_string(), div_euclid(&square, &modulus).1.to_string());
+            }
+        }
+    }
+
+    #[test]
+    fn test_reduce_edge_cases() {
+        let ctx = BarrettCtx::new(&LargeInt::parse("100"));
+        assert_eq!(ctx.reduce(&LargeInt::parse("9999")).to_string(), "99");
+        assert_eq!(ctx.reduce(&LargeInt::parse("100")).to_string(), "0");
+        assert_eq!(ctx.reduce(&LargeInt::parse("-1")).to_string(), "99");
+        assert_eq!(ctx.reduce(&LargeInt::parse("123456789")).to_string(), "89");
+    }
+
+    #[test]
+    #[should_panic(expected = "Barrett modulus must be positive!")]
+    fn test_zero_modulus() {
+        BarrettCtx::new(&LargeInt::zero());
+    }
+}
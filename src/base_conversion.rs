@@ -1,11 +1,41 @@
+use crate::{LargeInt, ParseErrorKind};
+use std::fmt;
+use std::num::IntErrorKind;
+
+/// Errors returned by the typed base-conversion functions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BaseConversionError {
+  /// The base was outside 2 to 36.
+  InvalidBase(u32),
+  /// The input contained no digits.
+  Empty,
+  /// A character was not a valid digit for the base.
+  InvalidDigit(char),
+  /// The value does not fit in the result type.
+  Overflow,
+}
+
+impl fmt::Display for BaseConversionError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+      match self {
+          BaseConversionError::InvalidBase(base) => write!(f, "Base {} is not between 2 and 36", base),
+          BaseConversionError::Empty => write!(f, "No digits to convert"),
+          BaseConversionError::InvalidDigit(c) => write!(f, "Invalid digit '{}' for the given base", c),
+          BaseConversionError::Overflow => write!(f, "Number is too large to convert"),
+      }
+  }
+}
+
+impl std::error::Error for BaseConversionError {}
+
 /// Converts a number from one base to another.
 /// Supports bases from 2 to 36.
 pub fn convert_base(number: &str, from_base: u32, to_base: u32) -> Result<String, String> {
-  if from_base < 2 || from_base > 36 || to_base < 2 || to_base > 36 {
+  if !(2..=36).contains(&from_base) || !(2..=36).contains(&to_base) {
       return Err("Base must be between 2 and 36".to_string());
   }
 
-  let decimal_value = match u128::from_str_radix(number, from_base) {
+  let decimal_value = match u128::from_str_radix(&strip_separators(number), from_base) {
       Ok(value) => value,
       Err(_) => return Err("Invalid number for the given base".to_string()),
   };
@@ -13,6 +43,41 @@ pub fn convert_base(number: &str, from_base: u32, to_base: u32) -> Result<String
   Ok(decimal_to_base(decimal_value, to_base))
 }
 
+/// Checks that converting `number` from `base_a` to `base_b` and back gives the
+/// original value, ignoring case, separators and leading zeros.
+/// Returns false if either conversion fails.
+pub fn verify_round_trip(number: &str, base_a: u32, base_b: u32) -> bool {
+  round_trips_with(number, base_a, base_b, convert_base)
+}
+
+fn round_trips_with(
+  number: &str,
+  base_a: u32,
+  base_b: u32,
+  convert: impl Fn(&str, u32, u32) -> Result<String, String>,
+) -> bool {
+  let there = match convert(number, base_a, base_b) {
+      Ok(value) => value,
+      Err(_) => return false,
+  };
+  match convert(&there, base_b, base_a) {
+      Ok(back) => canonical_digits(&back) == canonical_digits(number),
+      Err(_) => false,
+  }
+}
+
+/// Lowercases and strips separators and leading zeros so equal values compare equal.
+fn canonical_digits(number: &str) -> String {
+  let digits = strip_separators(number).to_lowercase();
+  let trimmed = digits.trim_start_matches('0');
+  if trimmed.is_empty() { "0".to_string() } else { trimmed.to_string() }
+}
+
+/// Removes surrounding whitespace and `_` digit-group separators from user input.
+fn strip_separators(number: &str) -> String {
+  number.trim().chars().filter(|&c| c != '_').collect()
+}
+
 /// Converts a decimal number to a string representation in the specified base.
 fn decimal_to_base(mut number: u128, base: u32) -> String {
   if number == 0 {
@@ -34,18 +99,83 @@ fn decimal_to_base(mut number: u128, base: u32) -> String {
   result.chars().rev().collect()
 }
 
+/// Like `decimal_to_base`, but inserts `sep` every `group` digits counted
+/// from the right, e.g. `1010_0110`. A `group` of zero disables grouping.
+pub fn decimal_to_base_grouped(number: u128, base: u32, group: usize, sep: char) -> String {
+  let digits = decimal_to_base(number, base);
+  if group == 0 {
+      return digits;
+  }
+
+  let mut result = String::with_capacity(digits.len() + digits.len() / group);
+  for (i, digit) in digits.chars().enumerate() {
+      if i > 0 && (digits.len() - i).is_multiple_of(group) {
+          result.push(sep);
+      }
+      result.push(digit);
+  }
+  result
+}
+
 /// Converts a number from a given base to decimal.
 pub fn to_decimal(number: &str, from_base: u32) -> Result<u128, String> {
-  if from_base < 2 || from_base > 36 {
+  if !(2..=36).contains(&from_base) {
       return Err("Base must be between 2 and 36".to_string());
   }
 
-  match u128::from_str_radix(number, from_base) {
+  match u128::from_str_radix(&strip_separators(number), from_base) {
       Ok(value) => Ok(value),
       Err(_) => Err("Invalid number for the given base".to_string()),
   }
 }
 
+/// Converts a number from a given base to decimal, accepting a leading `-` or `+`.
+/// Values outside the `i128` range give `BaseConversionError::Overflow`.
+pub fn to_decimal_signed(number: &str, from_base: u32) -> Result<i128, BaseConversionError> {
+  if !(2..=36).contains(&from_base) {
+      return Err(BaseConversionError::InvalidBase(from_base));
+  }
+
+  let digits = strip_separators(number);
+  i128::from_str_radix(&digits, from_base).map_err(|err| match err.kind() {
+      IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => BaseConversionError::Overflow,
+      IntErrorKind::InvalidDigit => {
+          let body = digits.strip_prefix(['-', '+']).unwrap_or(&digits);
+          match body.chars().find(|c| !c.is_digit(from_base)) {
+              Some(c) => BaseConversionError::InvalidDigit(c),
+              None => BaseConversionError::Empty,
+          }
+      }
+      _ => BaseConversionError::Empty,
+  })
+}
+
+/// Converts a number of any length from a given base into a LargeInt,
+/// accepting a leading `-` or `+` and `_` separators.
+pub fn to_large_int(number: &str, from_base: u32) -> Result<LargeInt, BaseConversionError> {
+  LargeInt::from_str_radix(&strip_separators(number), from_base).map_err(|err| match err.kind {
+      ParseErrorKind::Empty | ParseErrorKind::LoneSign => BaseConversionError::Empty,
+      ParseErrorKind::InvalidDigit(digit) => BaseConversionError::InvalidDigit(digit),
+      ParseErrorKind::InvalidRadix => BaseConversionError::InvalidBase(from_base),
+      ParseErrorKind::NotAnInteger | ParseErrorKind::ExponentTooLarge => {
+          unreachable!("only scientific notation parsing reports {:?}", err)
+      }
+  })
+}
+
+/// Interactively reads a number and a pair of bases, then prints the conversion.
+/// `read_line` shows a prompt and returns the trimmed line the user entered.
+pub fn run_repl(read_line: &mut dyn FnMut(&str) -> String) {
+  let number = read_line("Enter the number to convert: ");
+  let from_base = read_line("Enter the source base (2-36): ").parse::<u32>().unwrap_or(0);
+  let to_base = read_line("Enter the target base (2-36): ").parse::<u32>().unwrap_or(0);
+
+  match convert_base(&number, from_base, to_base) {
+      Ok(result) => println!("Result: {}", result),
+      Err(err) => println!("Error: {}", err),
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -59,10 +189,83 @@ mod tests {
       assert!(convert_base("1010", 2, 37).is_err());
   }
 
+  #[test]
+  fn test_separators_and_whitespace() {
+      assert_eq!(convert_base("1_0_1_0", 2, 10).unwrap(), "10");
+      assert_eq!(convert_base("1_010_0110", 2, 16).unwrap(), "a6");
+      assert_eq!(convert_base(" FF ", 16, 10).unwrap(), "255");
+      assert_eq!(to_decimal("\tff\n", 16).unwrap(), 255);
+      assert!(convert_base("1_2", 2, 10).is_err());
+      assert!(convert_base("f f", 16, 10).is_err());
+  }
+
   #[test]
   fn test_to_decimal() {
       assert_eq!(to_decimal("1010", 2).unwrap(), 10);
       assert_eq!(to_decimal("a", 16).unwrap(), 10);
       assert!(to_decimal("1010", 37).is_err());
   }
+
+  #[test]
+  fn test_verify_round_trip() {
+      assert!(verify_round_trip("1010", 2, 10));
+      assert!(verify_round_trip("00FF", 16, 2));
+      assert!(verify_round_trip("dead_beef", 16, 36));
+      assert!(verify_round_trip("0", 10, 7));
+      assert!(!verify_round_trip("12", 2, 10));
+      assert!(!verify_round_trip("10", 10, 40));
+  }
+
+  #[test]
+  fn test_round_trip_detects_broken_converter() {
+      // Drops the last output digit whenever converting into base 2.
+      let broken = |number: &str, from: u32, to: u32| {
+          let mut result = convert_base(number, from, to)?;
+          if to == 2 && result.len() > 1 {
+              result.pop();
+          }
+          Ok(result)
+      };
+      assert!(!round_trips_with("255", 10, 2, broken));
+      assert!(round_trips_with("255", 10, 2, convert_base));
+  }
+
+  #[test]
+  fn test_decimal_to_base_grouped() {
+      assert_eq!(decimal_to_base_grouped(0xa6, 2, 4, '_'), "1010_0110");
+      assert_eq!(decimal_to_base_grouped(5, 2, 4, '_'), "101");
+      assert_eq!(decimal_to_base_grouped(0xdeadbeef, 16, 4, '_'), "dead_beef");
+      assert_eq!(decimal_to_base_grouped(0x1deadbeef, 16, 4, ' '), "1 dead beef");
+      assert_eq!(decimal_to_base_grouped(255, 16, 0, '_'), "ff");
+      let grouped = decimal_to_base_grouped(0xa6, 2, 4, '_');
+      assert_eq!(to_decimal(&grouped, 2), Ok(0xa6));
+  }
+
+  #[test]
+  fn test_to_decimal_signed() {
+      assert_eq!(to_decimal_signed("-ff", 16), Ok(-255));
+      assert_eq!(to_decimal_signed("+1_010", 2), Ok(10));
+      assert_eq!(to_decimal_signed("-0", 10), Ok(0));
+      assert_eq!(to_decimal_signed(&format!("-{}", 1u128 << 127), 10), Ok(i128::MIN));
+      assert_eq!(to_decimal_signed(&(1u128 << 127).to_string(), 10), Err(BaseConversionError::Overflow));
+      assert_eq!(to_decimal_signed("-12", 2), Err(BaseConversionError::InvalidDigit('2')));
+      assert_eq!(to_decimal_signed("-", 10), Err(BaseConversionError::Empty));
+      assert_eq!(to_decimal_signed("7", 1), Err(BaseConversionError::InvalidBase(1)));
+  }
+
+  #[test]
+  fn test_to_large_int() {
+      let hex = "fedcba9876543210fedcba9876543210fedcba9876543210fedcba987654";
+      assert_eq!(hex.len(), 60);
+      let value = to_large_int(hex, 16).unwrap();
+      assert_eq!(
+          value.to_string(),
+          "1758994411157147065998426992352898135208789998015296191130825551269885524"
+      );
+      assert_eq!(value.to_str_radix(16), hex);
+      assert_eq!(to_large_int("-ff_ff", 16).unwrap().to_string(), "-65535");
+      assert_eq!(to_large_int("12", 2).unwrap_err(), BaseConversionError::InvalidDigit('2'));
+      assert_eq!(to_large_int("", 10).unwrap_err(), BaseConversionError::Empty);
+      assert_eq!(to_large_int("1", 40).unwrap_err(), BaseConversionError::InvalidBase(40));
+  }
 }
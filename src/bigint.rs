@@ -1,26 +1,123 @@
 use std::cmp::Ordering;
+use std::fmt;
+use num::{Num, One, Zero};
+use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Rem, Sub};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Operand size (in digits) from which `add_same_sign` sums chunks in parallel.
+#[cfg(feature = "parallel")]
+const PARALLEL_ADD_THRESHOLD: usize = 1 << 20;
+
+/// Digits per chunk for the parallel addition.
+#[cfg(feature = "parallel")]
+const PARALLEL_ADD_CHUNK: usize = 1 << 16;
 
 /// Represents an arbitrarily large integer.
-#[derive(Debug, Clone)]
+/// Values are always normalized, so equal numbers have equal fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct LargeInt {
-    pub sign: i8,          // 1 for positive, -1 for negative
-    pub digits: Vec<u8>,   // Digits stored in reverse order
+    pub(crate) sign: i8,          // 1 for positive, -1 for negative
+    pub(crate) digits: Vec<u8>,   // Digits stored in reverse order
 }
 
 impl LargeInt {
     /// Creates a new LargeInt from a sign and a vector of digits.
+    /// Panics if the sign is not 1 or -1 or a digit is 10 or more.
     pub fn new(sign: i8, digits: Vec<u8>) -> Self {
-        let mut digits = digits;
-        // Normalize by removing leading zeros
-        while digits.len() > 1 && digits.last() == Some(&0) {
-            digits.pop();
+        let mut result = LargeInt { sign, digits };
+        result.canonicalize();
+        result
+    }
+
+    /// Builds a non-negative LargeInt from decimal digits, least significant first.
+    /// Values are currently stored as exactly these digits; this constructor is
+    /// the stable way to build from decimal digit vectors whatever the storage
+    /// base becomes. Panics if any digit is 10 or more.
+    pub fn from_decimal_digits(digits: Vec<u8>) -> Self {
+        LargeInt::new(1, digits)
+    }
+
+    /// Brings a possibly hand-built value into canonical form: validates the
+    /// sign and digit ranges, then strips leading zeros and fixes negative zero.
+    /// Panics if the sign is not 1 or -1 or a digit is 10 or more.
+    pub fn canonicalize(&mut self) {
+        if self.sign != 1 && self.sign != -1 {
+            panic!("Sign must be 1 or -1!");
+        }
+        if self.digits.iter().any(|&d| d >= 10) {
+            panic!("Decimal digit must be below 10!");
+        }
+        self.normalize();
+    }
+
+    /// Removes leading zeros and makes zero positive.
+    pub fn normalize(&mut self) {
+        while self.digits.len() > 1 && self.digits.last() == Some(&0) {
+            self.digits.pop();
+        }
+        if self.digits.is_empty() {
+            self.digits.push(0);
         }
         // Zero is always positive
-        if digits.len() == 1 && digits[0] == 0 {
-            LargeInt { sign: 1, digits }
-        } else {
-            LargeInt { sign, digits }
+        if self.is_zero() {
+            self.sign = 1;
+        }
+    }
+
+    /// Returns 1 for positive values (including zero) and -1 for negative values.
+    pub fn sign(&self) -> i8 {
+        self.sign
+    }
+
+    /// Returns the decimal digits of the absolute value, least significant first.
+    pub fn decimal_digits(&self) -> &[u8] {
+        &self.digits
+    }
+
+    /// Returns the number of decimal digits in the absolute value.
+    pub fn num_digits(&self) -> usize {
+        self.digits.len()
+    }
+
+    /// Returns the decimal digit at `index` (0 is the least significant),
+    /// or 0 past the most significant digit.
+    pub fn digit_at(&self, index: usize) -> u8 {
+        self.digits.get(index).copied().unwrap_or(0)
+    }
+
+    /// Replaces the decimal digit at `index`, extending with zeros if needed,
+    /// then re-normalizes so clearing the top digit drops leading zeros.
+    /// Panics if `value` is not below 10.
+    pub fn set_digit(&mut self, index: usize, value: u8) {
+        if value >= 10 {
+            panic!("Decimal digit must be below 10!");
         }
+        if index >= self.digits.len() {
+            self.digits.resize(index + 1, 0);
+        }
+        self.digits[index] = value;
+        self.normalize();
+    }
+
+    /// Cyclically rotates the decimal digits of the magnitude towards the most
+    /// significant end, e.g. `12345` by 2 gives `34512`. The sign is kept and
+    /// any leading zeros produced are dropped.
+    pub fn rotate_digits_left(&self, by: usize) -> Self {
+        let mut digits = self.digits.clone();
+        let len = digits.len();
+        digits.rotate_right(by % len);
+        LargeInt::new(self.sign, digits)
+    }
+
+    /// Cyclically rotates the decimal digits of the magnitude towards the least
+    /// significant end, e.g. `12345` by 2 gives `45123`. The sign is kept and
+    /// any leading zeros produced are dropped.
+    pub fn rotate_digits_right(&self, by: usize) -> Self {
+        let mut digits = self.digits.clone();
+        let len = digits.len();
+        digits.rotate_left(by % len);
+        LargeInt::new(self.sign, digits)
     }
 
     /// Constructs a LargeInt representing zero.
@@ -34,25 +131,351 @@ impl LargeInt {
         let sign = if input.starts_with('-') { -1 } else { 1 };
         let digits = input
             .chars()
-            .filter(|c| c.is_digit(10))
+            .filter(|c| c.is_ascii_digit())
             .map(|c| c.to_digit(10).unwrap() as u8)
             .rev()
             .collect();
         LargeInt::new(sign, digits)
     }
 
-    /// Converts the LargeInt back into a string.
-    pub fn to_string(&self) -> String {
-        let mut result = self
+    /// Parses a string of digits in the given radix (2 to 36), with an optional sign.
+    /// Every character must be a digit below the radix; the first one that is not
+    /// is reported with its byte offset. Power-of-two radixes pack bits straight
+    /// into binary limbs.
+    pub fn from_str_radix(input: &str, radix: u32) -> Result<Self, ParseLargeIntError> {
+        if !(2..=36).contains(&radix) {
+            return Err(ParseLargeIntError::new(0, ParseErrorKind::InvalidRadix));
+        }
+
+        let (sign, body) = match input.strip_prefix('-') {
+            Some(rest) => (-1, rest),
+            None => (1, input.strip_prefix('+').unwrap_or(input)),
+        };
+        let sign_len = input.len() - body.len();
+        if body.is_empty() {
+            return Err(ParseLargeIntError::missing_digits(sign_len, sign_len));
+        }
+
+        let mut values = Vec::with_capacity(body.len());
+        for (offset, c) in body.char_indices() {
+            match c.to_digit(radix) {
+                Some(value) => values.push(value as u8),
+                None => {
+                    let kind = ParseErrorKind::InvalidDigit(c);
+                    return Err(ParseLargeIntError::new(sign_len + offset, kind));
+                }
+            }
+        }
+
+        let magnitude = if radix.is_power_of_two() {
+            LargeInt::from_power_of_two_digits(&values, radix.trailing_zeros())
+        } else {
+            LargeInt::from_radix_digits(&values, radix)
+        };
+        Ok(LargeInt::new(sign, magnitude.digits))
+    }
+
+    /// Parses an integer whose radix is given by a `0x`, `0o` or `0b` prefix,
+    /// defaulting to decimal, with an optional sign before the prefix.
+    /// Error positions are byte offsets into the original input.
+    pub fn parse_auto(input: &str) -> Result<Self, ParseLargeIntError> {
+        let (sign, body) = match input.strip_prefix('-') {
+            Some(rest) => (-1, rest),
+            None => (1, input.strip_prefix('+').unwrap_or(input)),
+        };
+        let (radix, digits) = match body.get(..2) {
+            Some("0x") | Some("0X") => (16, &body[2..]),
+            Some("0o") | Some("0O") => (8, &body[2..]),
+            Some("0b") | Some("0B") => (2, &body[2..]),
+            _ => (10, body),
+        };
+        let offset = input.len() - digits.len();
+        if let Some(c) = digits.chars().next().filter(|c| matches!(c, '-' | '+')) {
+            return Err(ParseLargeIntError::new(offset, ParseErrorKind::InvalidDigit(c)));
+        }
+        if body.is_empty() {
+            return Err(ParseLargeIntError::missing_digits(offset, offset));
+        }
+
+        let magnitude = LargeInt::from_str_radix(digits, radix).map_err(|err| ParseLargeIntError {
+            position: offset + err.position,
+            ..err
+        })?;
+        Ok(LargeInt::new(sign, magnitude.digits))
+    }
+
+    /// Parses ASCII decimal digits with an optional sign straight from a byte
+    /// buffer, without building a `String` first. The first byte outside
+    /// `b'0'..=b'9'` is reported with its offset.
+    pub fn from_ascii_bytes(bytes: &[u8]) -> Result<Self, ParseLargeIntError> {
+        let (sign, body) = match bytes.split_first() {
+            Some((b'-', rest)) => (-1, rest),
+            Some((b'+', rest)) => (1, rest),
+            _ => (1, bytes),
+        };
+        if body.is_empty() {
+            return Err(ParseLargeIntError::missing_digits(bytes.len(), bytes.len()));
+        }
+
+        if let Some(offset) = body.iter().position(|b| !b.is_ascii_digit()) {
+            let position = bytes.len() - body.len() + offset;
+            let kind = ParseErrorKind::InvalidDigit(bytes[position] as char);
+            return Err(ParseLargeIntError::new(position, kind));
+        }
+        let digits = body.iter().rev().map(|b| b - b'0').collect();
+        Ok(LargeInt::new(sign, digits))
+    }
+
+    /// Parses decimal or scientific notation such as `"1.5e10"` or `"-2E3"`.
+    /// The mantissa may have a fractional part and the exponent must be a
+    /// non-negative integer; the value itself must come out integral.
+    pub fn parse_scientific(input: &str) -> Result<Self, ParseLargeIntError> {
+        let (sign, body) = match input.strip_prefix('-') {
+            Some(rest) => (-1, rest),
+            None => (1, input.strip_prefix('+').unwrap_or(input)),
+        };
+        let sign_len = input.len() - body.len();
+        let (mantissa, exponent) = match body.find(['e', 'E']) {
+            Some(at) => (&body[..at], Some((at + 1, &body[at + 1..]))),
+            None => (body, None),
+        };
+
+        let error = |offset: usize, kind| ParseLargeIntError::new(sign_len + offset, kind);
+        let mut digits = Vec::with_capacity(mantissa.len());
+        let mut point = None;
+        for (offset, c) in mantissa.char_indices() {
+            match c {
+                '0'..='9' => digits.push(c as u8 - b'0'),
+                '.' if point.is_none() => point = Some((offset, digits.len())),
+                _ => return Err(error(offset, ParseErrorKind::InvalidDigit(c))),
+            }
+        }
+        if digits.is_empty() {
+            return Err(ParseLargeIntError::missing_digits(sign_len, sign_len + mantissa.len()));
+        }
+
+        let shift = match exponent {
+            None => 0,
+            Some((start, text)) => {
+                let unsigned = text.strip_prefix('+').unwrap_or(text);
+                if text.starts_with('-') {
+                    return Err(error(start, ParseErrorKind::NotAnInteger));
+                }
+                let skipped = text.len() - unsigned.len();
+                if unsigned.is_empty() {
+                    return Err(error(start + skipped, ParseErrorKind::Empty));
+                }
+                if let Some((offset, c)) = unsigned.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+                    return Err(error(start + skipped + offset, ParseErrorKind::InvalidDigit(c)));
+                }
+                unsigned
+                    .parse::<usize>()
+                    .map_err(|_| error(start + skipped, ParseErrorKind::ExponentTooLarge))?
+            }
+        };
+
+        // Fractional digits beyond the exponent must all be zero.
+        if let Some((point_offset, whole_len)) = point {
+            let fraction_len = digits.len() - whole_len;
+            if fraction_len > shift {
+                let kept = whole_len + shift;
+                if let Some(index) = digits[kept..].iter().position(|&d| d != 0) {
+                    return Err(error(point_offset + 1 + shift + index, ParseErrorKind::NotAnInteger));
+                }
+                digits.truncate(kept);
+            }
+        }
+        let fraction_len = point.map_or(0, |(_, whole_len)| digits.len() - whole_len);
+
+        digits.reverse();
+        let mantissa = LargeInt::new(sign, digits);
+        Ok(crate::multiply(&mantissa, &LargeInt::pow10(shift.saturating_sub(fraction_len))))
+    }
+
+    /// Multiplies the value by 10 and adds `d` (`12` becomes `123`, `-12`
+    /// becomes `-117`), for building values digit by digit from a stream.
+    /// Each call shifts the existing digits, so prefer `push_decimal_digits`
+    /// with whole buffers when streaming long inputs.
+    /// Panics if `d` is not below 10.
+    pub fn push_decimal_digit(&mut self, d: u8) {
+        self.push_decimal_digits(&[d]);
+    }
+
+    /// Multiplies the value by `10^k` and adds the `k` most-significant-first
+    /// decimal digits in `ds`, shifting the existing digits only once.
+    /// Panics if any digit is 10 or more.
+    pub fn push_decimal_digits(&mut self, ds: &[u8]) {
+        if ds.iter().any(|&d| d >= 10) {
+            panic!("Decimal digit must be below 10!");
+        }
+        if ds.is_empty() {
+            return;
+        }
+        let mut digits: Vec<u8> = ds.iter().rev().copied().collect();
+        if self.is_zero() {
+            self.sign = 1;
+        } else {
+            digits.extend_from_slice(&self.digits);
+        }
+
+        // For negatives the pushed digits are subtracted from the shifted
+        // magnitude, which is at least 10^k and so never goes negative.
+        if self.sign < 0 {
+            let mut borrow = 0;
+            for digit in digits.iter_mut().take(ds.len()) {
+                let sub = *digit + borrow;
+                (*digit, borrow) = if sub == 0 { (0, 0) } else { (10 - sub, 1) };
+            }
+            for digit in digits.iter_mut().skip(ds.len()) {
+                if borrow == 0 {
+                    break;
+                }
+                (*digit, borrow) = if *digit == 0 { (9, 1) } else { (*digit - 1, 0) };
+            }
+        }
+        *self = LargeInt::new(self.sign, digits);
+    }
+
+    /// Accumulates most-significant-first digit values by repeated multiply-by-radix.
+    fn from_radix_digits(values: &[u8], radix: u32) -> Self {
+        let radix = LargeInt::small(radix as u8);
+        values
+            .iter()
+            .fold(LargeInt::zero(), |acc, &d| acc.mul_add(&radix, &LargeInt::small(d)))
+    }
+
+    /// Packs most-significant-first digits of `bits_per_digit` bits each into binary limbs.
+    fn from_power_of_two_digits(values: &[u8], bits_per_digit: u32) -> Self {
+        let mut limbs = Vec::new();
+        let mut buffer: u64 = 0;
+        let mut filled = 0;
+        for &value in values.iter().rev() {
+            buffer |= (value as u64) << filled;
+            filled += bits_per_digit;
+            if filled >= 32 {
+                limbs.push(buffer as u32);
+                buffer >>= 32;
+                filled -= 32;
+            }
+        }
+        if filled > 0 {
+            limbs.push(buffer as u32);
+        }
+        LargeInt::from_binary_limbs(1, &limbs)
+    }
+
+    /// Renders the LargeInt in the given radix (2 to 36) with lowercase letters.
+    /// Panics if the radix is out of range.
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        if !(2..=36).contains(&radix) {
+            panic!("Radix must be between 2 and 36");
+        }
+
+        let mut digits = self.digits.clone();
+        let mut result = Vec::new();
+        loop {
+            // Divide the decimal digits by the radix in place, keeping the remainder.
+            let mut remainder = 0;
+            for digit in digits.iter_mut().rev() {
+                let current = remainder * 10 + *digit as u32;
+                *digit = (current / radix) as u8;
+                remainder = current % radix;
+            }
+            while digits.len() > 1 && digits.last() == Some(&0) {
+                digits.pop();
+            }
+            result.push(char::from_digit(remainder, radix).unwrap());
+            if digits.len() == 1 && digits[0] == 0 {
+                break;
+            }
+        }
+
+        if self.sign == -1 {
+            result.push('-');
+        }
+        result.iter().rev().collect()
+    }
+
+    /// Renders the LargeInt with `,` between groups of three digits, e.g. "1,234,567".
+    pub fn to_grouped_string(&self) -> String {
+        let digits: Vec<char> = self.digits.iter().map(|&d| (b'0' + d) as char).collect();
+        let groups: Vec<String> = digits
+            .chunks(3)
+            .rev()
+            .map(|group| group.iter().rev().collect())
+            .collect();
+        let sign = if self.sign == -1 { "-" } else { "" };
+        format!("{}{}", sign, groups.join(","))
+    }
+
+    /// Renders the LargeInt in scientific notation with all significant digits,
+    /// e.g. "1.5e3" for 1500.
+    pub fn to_scientific_string(&self) -> String {
+        let exponent = self.digits.len() - 1;
+        let significant: String = self
             .digits
             .iter()
             .rev()
-            .map(|&d| char::from_digit(d as u32, 10).unwrap())
-            .collect::<String>();
+            .map(|&d| (b'0' + d) as char)
+            .collect::<String>()
+            .trim_end_matches('0')
+            .to_string();
+        let sign = if self.sign == -1 { "-" } else { "" };
+        match significant.len() {
+            0 => "0e0".to_string(),
+            1 => format!("{}{}e{}", sign, significant, exponent),
+            _ => format!("{}{}.{}e{}", sign, &significant[..1], &significant[1..], exponent),
+        }
+    }
+
+    /// Renders the LargeInt in English words, e.g. `1234` gives
+    /// "one thousand two hundred thirty-four". Scale names run up to decillions;
+    /// values too large for them are spelled out digit by digit.
+    pub fn to_english_words(&self) -> String {
+        const ONES: [&str; 20] = [
+            "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+            "eleven", "twelve", "thirteen", "fourteen", "fifteen", "sixteen", "seventeen",
+            "eighteen", "nineteen",
+        ];
+        const TENS: [&str; 10] = [
+            "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+        ];
+        const SCALES: [&str; 12] = [
+            "", "thousand", "million", "billion", "trillion", "quadrillion", "quintillion",
+            "sextillion", "septillion", "octillion", "nonillion", "decillion",
+        ];
+
+        let mut words = Vec::new();
         if self.sign == -1 {
-            result.insert(0, '-');
+            words.push("negative".to_string());
         }
-        result
+
+        if self.is_zero() {
+            words.push(ONES[0].to_string());
+        } else if self.digits.len() > SCALES.len() * 3 {
+            words.extend(self.digits.iter().rev().map(|&d| ONES[d as usize].to_string()));
+        } else {
+            for (scale, group) in self.digits.chunks(3).enumerate().rev() {
+                let value = group.iter().rev().fold(0, |acc, &d| acc * 10 + d as usize);
+                if value == 0 {
+                    continue;
+                }
+                if value >= 100 {
+                    words.push(format!("{} hundred", ONES[value / 100]));
+                }
+                match value % 100 {
+                    0 => {}
+                    rest if rest < 20 => words.push(ONES[rest].to_string()),
+                    rest if rest % 10 == 0 => words.push(TENS[rest / 10].to_string()),
+                    rest => words.push(format!("{}-{}", TENS[rest / 10], ONES[rest % 10])),
+                }
+                if scale > 0 {
+                    words.push(SCALES[scale].to_string());
+                }
+            }
+        }
+
+        words.join(" ")
     }
 
     /// Compares the absolute values of two LargeInts.
@@ -68,11 +491,21 @@ impl LargeInt {
         Ordering::Equal
     }
 
+    /// Compares two LargeInts numerically, taking signs into account.
+    pub(crate) fn compare_signed(&self, other: &Self) -> Ordering {
+        match (self.sign, other.sign) {
+            (1, -1) => Ordering::Greater,
+            (-1, 1) => Ordering::Less,
+            (1, 1) => self.compare_abs(other),
+            _ => other.compare_abs(self),
+        }
+    }
+
     /// Pads the digits of two LargeInts to equal lengths.
     pub fn pad_equal_lengths(a: &Self, b: &Self) -> (Vec<u8>, Vec<u8>) {
         let max_len = a.digits.len().max(b.digits.len());
-        let a_padded = a.digits.iter().cloned().chain(std::iter::repeat(0).take(max_len - a.digits.len())).collect();
-        let b_padded = b.digits.iter().cloned().chain(std::iter::repeat(0).take(max_len - b.digits.len())).collect();
+        let a_padded = a.digits.iter().cloned().chain(std::iter::repeat_n(0, max_len - a.digits.len())).collect();
+        let b_padded = b.digits.iter().cloned().chain(std::iter::repeat_n(0, max_len - b.digits.len())).collect();
         (a_padded, b_padded)
     }
 
@@ -86,11 +519,7 @@ impl LargeInt {
                 result
             }
             (1, -1) => self.subtract_abs(other),
-            (-1, 1) => {
-                let mut result = other.subtract_abs(self);
-                result.sign = -1;
-                result
-            }
+            (-1, 1) => other.subtract_abs(self),
             _ => unreachable!(),
         }
     }
@@ -99,11 +528,7 @@ impl LargeInt {
     pub fn subtract(&self, other: &Self) -> Self {
         match (self.sign, other.sign) {
             (1, 1) => self.subtract_abs(other),
-            (-1, -1) => {
-                let mut result = other.subtract_abs(self);
-                result.sign = -result.sign;
-                result
-            }
+            (-1, -1) => other.subtract_abs(self),
             (1, -1) => self.add_same_sign(other),
             (-1, 1) => {
                 let mut result = self.add_same_sign(other);
@@ -115,13 +540,25 @@ impl LargeInt {
     }
 
     /// Adds two LargeInts with the same sign.
+    /// Operands of a million digits or more are summed chunk by chunk on the
+    /// Rayon thread pool when the `parallel` feature is on.
     pub fn add_same_sign(&self, other: &Self) -> Self {
+        #[cfg(feature = "parallel")]
+        if self.digits.len().max(other.digits.len()) >= PARALLEL_ADD_THRESHOLD {
+            return self.add_same_sign_parallel(other, PARALLEL_ADD_CHUNK);
+        }
+
+        self.add_same_sign_seq(other)
+    }
+
+    /// Sequential digit-by-digit addition behind `add_same_sign`.
+    pub(crate) fn add_same_sign_seq(&self, other: &Self) -> Self {
         let mut result_digits = Vec::new();
         let mut carry = 0;
 
         let (a_padded, b_padded) = LargeInt::pad_equal_lengths(self, other);
 
-        for (a, b) in a_padded.into_iter().zip(b_padded.into_iter()) {
+        for (a, b) in a_padded.into_iter().zip(b_padded) {
             let sum = a + b + carry;
             result_digits.push(sum % 10);
             carry = sum / 10;
@@ -134,14 +571,72 @@ impl LargeInt {
         LargeInt::new(self.sign, result_digits)
     }
 
+    /// Parallel addition behind `add_same_sign`. Each chunk of `chunk` digits
+    /// is summed independently as if no carry came in; a sequential pass over
+    /// the chunks then works out which ones do receive a carry (a chunk of all
+    /// nines passes one straight through), and those are fixed up in parallel.
+    #[cfg(feature = "parallel")]
+    pub(crate) fn add_same_sign_parallel(&self, other: &Self, chunk: usize) -> Self {
+        let (a_padded, b_padded) = LargeInt::pad_equal_lengths(self, other);
+        let mut result_digits = vec![0u8; a_padded.len()];
+
+        let chunk_carries: Vec<(bool, bool)> = result_digits
+            .par_chunks_mut(chunk)
+            .zip(a_padded.par_chunks(chunk).zip(b_padded.par_chunks(chunk)))
+            .map(|(out, (a, b))| {
+                let mut carry = 0;
+                for (digit, (a, b)) in out.iter_mut().zip(a.iter().zip(b)) {
+                    let sum = a + b + carry;
+                    *digit = sum % 10;
+                    carry = sum / 10;
+                }
+                (carry == 1, out.iter().all(|&d| d == 9))
+            })
+            .collect();
+
+        let mut carry = false;
+        let carries_in: Vec<bool> = chunk_carries
+            .iter()
+            .map(|&(carry_out, all_nines)| {
+                let carry_in = carry;
+                carry = carry_out || (carry_in && all_nines);
+                carry_in
+            })
+            .collect();
+
+        result_digits
+            .par_chunks_mut(chunk)
+            .zip(carries_in)
+            .filter(|(_, carry_in)| *carry_in)
+            .for_each(|(out, _)| {
+                for digit in out.iter_mut() {
+                    if *digit == 9 {
+                        *digit = 0;
+                    } else {
+                        *digit += 1;
+                        break;
+                    }
+                }
+            });
+
+        if carry {
+            result_digits.push(1);
+        }
+        LargeInt::new(self.sign, result_digits)
+    }
+
     /// Subtracts the absolute values of two LargeInts.
     pub fn subtract_abs(&self, other: &Self) -> Self {
         match self.compare_abs(other) {
             Ordering::Equal => LargeInt::zero(),
-            Ordering::Greater => self.subtract_same_sign(other),
+            Ordering::Greater => {
+                let mut result = self.subtract_same_sign(other);
+                result.sign = 1;
+                result
+            }
             Ordering::Less => {
                 let mut result = other.subtract_same_sign(self);
-                result.sign = -result.sign;
+                result.sign = -1;
                 result
             }
         }
@@ -154,7 +649,7 @@ impl LargeInt {
 
         let (a_padded, b_padded) = LargeInt::pad_equal_lengths(self, other);
 
-        for (a, b) in a_padded.into_iter().zip(b_padded.into_iter()) {
+        for (a, b) in a_padded.into_iter().zip(b_padded) {
             let mut diff = a as i16 - b as i16 - borrow;
             if diff < 0 {
                 diff += 10;
@@ -167,45 +662,1216 @@ impl LargeInt {
 
         LargeInt::new(self.sign, result_digits)
     }
+
+    /// Returns `self - other` when `|self| >= |other|` and `self` unchanged
+    /// otherwise, comparing magnitudes only; the result keeps `self`'s sign.
+    /// This is the conditional final subtraction of modular reductions.
+    pub fn sub_if_ge(&self, other: &Self) -> Self {
+        if self.compare_abs(other) == Ordering::Less {
+            self.clone()
+        } else {
+            self.subtract_same_sign(other)
+        }
+    }
+
+    /// Computes `self * mul + add` in one call, e.g. for Horner's method.
+    pub fn mul_add(&self, mul: &Self, add: &Self) -> Self {
+        crate::multiply(self, mul).add(add)
+    }
+
+    /// Computes `self * self`, roughly twice as fast as `multiply(self, self)`:
+    /// each off-diagonal partial product is computed once and doubled.
+    /// The result is always non-negative.
+    pub fn square(&self) -> Self {
+        let n = self.digits.len();
+        let mut columns = vec![0u64; 2 * n];
+        for (i, &di) in self.digits.iter().enumerate() {
+            let di = di as u64;
+            columns[2 * i] += di * di;
+            let doubled = 2 * di;
+            for (j, &dj) in self.digits.iter().enumerate().skip(i + 1) {
+                columns[i + j] += doubled * dj as u64;
+            }
+        }
+
+        let mut result = Vec::with_capacity(2 * n);
+        let mut carry = 0;
+        for column in columns {
+            let temp = column + carry;
+            result.push((temp % 10) as u8);
+            carry = temp / 10;
+        }
+
+        let mut square = LargeInt::new(1, result);
+        square.normalize();
+        square
+    }
+
+    /// Computes the absolute value modulo a small modulus in one pass over the digits.
+    /// Panics if the modulus is zero.
+    pub fn rem_small(&self, modulus: u64) -> u64 {
+        if modulus == 0 {
+            panic!("Division by zero is not allowed!");
+        }
+        self.digits
+            .iter()
+            .rev()
+            .fold(0u128, |acc, &d| (acc * 10 + d as u128) % modulus as u128) as u64
+    }
+
+    /// Halves the absolute value, discarding any remainder.
+    pub(crate) fn halve(&self) -> Self {
+        let mut digits = vec![0; self.digits.len()];
+        let mut remainder = 0;
+        for i in (0..self.digits.len()).rev() {
+            let current = remainder * 10 + self.digits[i];
+            digits[i] = current / 2;
+            remainder = current % 2;
+        }
+        LargeInt::new(1, digits)
+    }
+
+    /// Converts the absolute value into base-2³² limbs, least significant first.
+    pub(crate) fn to_binary_limbs(&self) -> Vec<u32> {
+        let mut limbs = Vec::new();
+        let mut digits = self.digits.clone();
+        while !(digits.len() == 1 && digits[0] == 0) {
+            // Divide the decimal digits by 2³² in place, keeping the remainder.
+            let mut remainder: u64 = 0;
+            for digit in digits.iter_mut().rev() {
+                let current = remainder * 10 + *digit as u64;
+                *digit = (current >> 32) as u8;
+                remainder = current & 0xFFFF_FFFF;
+            }
+            while digits.len() > 1 && digits.last() == Some(&0) {
+                digits.pop();
+            }
+            limbs.push(remainder as u32);
+        }
+        limbs
+    }
+
+    /// Builds a LargeInt from base-2³² limbs, least significant first.
+    /// The limbs are folded into native base-10⁹ words (multiply by 2³², add
+    /// the next limb), which are then split into decimal digits, so no
+    /// LargeInt arithmetic happens per limb.
+    pub(crate) fn from_binary_limbs(sign: i8, limbs: &[u32]) -> Self {
+        const WORD: u64 = 1_000_000_000;
+        let mut words: Vec<u64> = vec![0];
+        for &limb in limbs.iter().rev() {
+            // Words are below 2³⁰, so the shifted value stays below 2⁶³.
+            let mut carry = limb as u64;
+            for word in words.iter_mut() {
+                let value = (*word << 32) + carry;
+                *word = value % WORD;
+                carry = value / WORD;
+            }
+            while carry > 0 {
+                words.push(carry % WORD);
+                carry /= WORD;
+            }
+        }
+
+        let mut digits = Vec::with_capacity(words.len() * 9);
+        for mut word in words {
+            for _ in 0..9 {
+                digits.push((word % 10) as u8);
+                word /= 10;
+            }
+        }
+        LargeInt::new(sign, digits)
+    }
+
+    /// Combines the binary magnitudes of two LargeInts limb by limb.
+    fn combine_bits(&self, other: &Self, op: impl Fn(u32, u32) -> u32) -> Self {
+        let a = self.to_binary_limbs();
+        let b = other.to_binary_limbs();
+        let limbs: Vec<u32> = (0..a.len().max(b.len()))
+            .map(|i| op(a.get(i).copied().unwrap_or(0), b.get(i).copied().unwrap_or(0)))
+            .collect();
+        LargeInt::from_binary_limbs(1, &limbs)
+    }
+
+    /// Returns the Luhn check digit to append to the decimal digits of the
+    /// absolute value, as used for credit-card numbers.
+    pub fn luhn_check_digit(&self) -> u8 {
+        // Once the check digit is appended, every digit of self sits in an odd position.
+        let sum = LargeInt::luhn_sum(&self.digits, true);
+        ((10 - sum % 10) % 10) as u8
+    }
+
+    /// Returns true if the decimal digits of the absolute value, whose last
+    /// digit is the check digit, pass the Luhn checksum.
+    pub fn is_valid_luhn(&self) -> bool {
+        LargeInt::luhn_sum(&self.digits, false).is_multiple_of(10)
+    }
+
+    /// Sums least-significant-first digits, doubling every second one
+    /// (starting with the first when `double_first`) and summing the doubled digits.
+    fn luhn_sum(digits: &[u8], double_first: bool) -> u32 {
+        digits
+            .iter()
+            .enumerate()
+            .map(|(i, &d)| {
+                let d = d as u32;
+                if (i % 2 == 0) == double_first {
+                    if d * 2 > 9 { d * 2 - 9 } else { d * 2 }
+                } else {
+                    d
+                }
+            })
+            .sum()
+    }
+
+    /// Returns the bits of the absolute value, least significant first,
+    /// without trailing zero bits. Zero gives an empty vector.
+    pub fn to_bits_le(&self) -> Vec<bool> {
+        let limbs = self.to_binary_limbs();
+        let mut bits: Vec<bool> = limbs
+            .iter()
+            .flat_map(|&limb| (0..32).map(move |bit| limb >> bit & 1 == 1))
+            .collect();
+        while bits.last() == Some(&false) {
+            bits.pop();
+        }
+        bits
+    }
+
+    /// Builds a LargeInt from least-significant-first bits and a sign.
+    pub fn from_bits_le(sign: i8, bits: &[bool]) -> Self {
+        let limbs: Vec<u32> = bits
+            .chunks(32)
+            .map(|chunk| chunk.iter().rev().fold(0, |acc, &bit| acc << 1 | bit as u32))
+            .collect();
+        LargeInt::from_binary_limbs(sign, &limbs)
+    }
+
+    /// Packs the decimal digits of the absolute value into base-10^group limbs,
+    /// least significant first, so `123456789` in groups of 3 is `[789, 456, 123]`.
+    /// Zero gives `[0]`. Panics unless `group` is 1 to 19, the widths whose
+    /// limbs always fit in a `u64`.
+    pub fn to_digit_groups(&self, group: usize) -> Vec<u64> {
+        if !(1..=19).contains(&group) {
+            panic!("Digit group size must be between 1 and 19!");
+        }
+        self.digits
+            .chunks(group)
+            .map(|chunk| chunk.iter().rev().fold(0, |acc, &d| acc * 10 + d as u64))
+            .collect()
+    }
+
+    /// Returns the number of 1 bits in the binary representation of the absolute value.
+    pub fn count_ones(&self) -> u64 {
+        self.to_binary_limbs()
+            .iter()
+            .map(|limb| limb.count_ones() as u64)
+            .sum()
+    }
+
+    /// Splits the absolute value into an odd part and a power of two.
+    /// Returns (odd, k) with |self| == odd * 2^k; zero returns (0, 0).
+    pub(crate) fn factor_out_twos(&self) -> (Self, u64) {
+        let mut odd = LargeInt::new(1, self.digits.clone());
+        let mut twos = 0;
+        while !odd.is_zero() && odd.digits[0].is_multiple_of(2) {
+            odd = odd.halve();
+            twos += 1;
+        }
+        (odd, twos)
+    }
+
+    /// Returns the number of bits needed to represent the absolute value.
+    /// Zero has a bit length of 0.
+    pub fn bit_length(&self) -> u64 {
+        let mut value = LargeInt::new(1, self.digits.clone());
+        let mut bits = 0;
+        while !value.is_zero() {
+            value = value.halve();
+            bits += 1;
+        }
+        bits
+    }
+
     pub fn is_zero(&self) -> bool {
         self.digits.len() == 1 && self.digits[0] == 0
     }
-    pub fn one() -> Self {
-        LargeInt::new(1, vec![1])
+
+    /// Checks whether the value is exactly one.
+    pub fn is_one(&self) -> bool {
+        self.sign == 1 && self.digits.len() == 1 && self.digits[0] == 1
     }
 
-}
+    /// Checks whether the value lies in `0..=255`.
+    pub fn fits_in_u8(&self) -> bool {
+        self.as_u8().is_some()
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Returns the value as a `u8` when it lies in `0..=255`.
+    pub fn as_u8(&self) -> Option<u8> {
+        if self.sign == -1 || self.digits.len() > 3 {
+            return None;
+        }
+        let value = self.digits.iter().rev().fold(0u16, |acc, &d| acc * 10 + d as u16);
+        u8::try_from(value).ok()
+    }
 
-    #[test]
-    fn test_addition() {
-        let a = LargeInt::parse("123");
-        let b = LargeInt::parse("456");
-        assert_eq!(a.add(&b).to_string(), "579");
+    /// Returns the value as a `usize`, or `None` when negative or above `usize::MAX`.
+    pub fn to_usize(&self) -> Option<usize> {
+        if self.sign == -1 && !self.is_zero() {
+            return None;
+        }
+        self.digits
+            .iter()
+            .rev()
+            .try_fold(0usize, |acc, &d| acc.checked_mul(10)?.checked_add(d as usize))
+    }
 
-        let a = LargeInt::parse("-123");
-        let b = LargeInt::parse("456");
-        assert_eq!(a.add(&b).to_string(), "333");
+    /// Checks whether the value lies in `0..=u64::MAX`.
+    pub fn fits_in_u64(&self) -> bool {
+        self.to_u64().is_some()
     }
 
-    #[test]
-    fn test_subtraction() {
-        let a = LargeInt::parse("456");
-        let b = LargeInt::parse("123");
-        assert_eq!(a.subtract(&b).to_string(), "333");
+    /// Returns the value as a `u64`, or `None` when negative or above `u64::MAX`.
+    pub fn to_u64(&self) -> Option<u64> {
+        if self.digits.len() > 20 {
+            return None;
+        }
+        self.to_u128().and_then(|n| u64::try_from(n).ok())
+    }
 
-        let a = LargeInt::parse("123");
-        let b = LargeInt::parse("456");
-        assert_eq!(a.subtract(&b).to_string(), "-333");
+    /// Returns the value as a `u128`, or `None` when negative or above `u128::MAX`.
+    pub fn to_u128(&self) -> Option<u128> {
+        if self.sign == -1 || self.digits.len() > 39 {
+            return None;
+        }
+        self.digits
+            .iter()
+            .rev()
+            .try_fold(0u128, |acc, &d| acc.checked_mul(10)?.checked_add(d as u128))
     }
 
-    #[test]
-    fn test_large_numbers() {
-        let a = LargeInt::parse("12345678901234567890");
-        let b = LargeInt::parse("98765432109876543210");
-        assert_eq!(a.add(&b).to_string(), "111111111011111111100");
+    /// Builds a LargeInt from a `u64`. Not a `From` impl, so that
+    /// `LargeInt::from(5)` keeps inferring `i64`.
+    pub(crate) fn from_u64(mut n: u64) -> Self {
+        let mut digits = vec![(n % 10) as u8];
+        n /= 10;
+        while n > 0 {
+            digits.push((n % 10) as u8);
+            n /= 10;
+        }
+        LargeInt::new(1, digits)
+    }
+
+    pub fn one() -> Self {
+        LargeInt::new(1, vec![1])
+    }
+
+    /// Constructs a small non-negative LargeInt directly from its digits, without parsing.
+    pub fn small(n: u8) -> Self {
+        let mut digits = vec![n % 10];
+        let mut rest = n / 10;
+        while rest > 0 {
+            digits.push(rest % 10);
+            rest /= 10;
+        }
+        LargeInt::new(1, digits)
+    }
+
+    /// Constructs 10^k directly as a one followed by k zero digits.
+    pub fn pow10(k: usize) -> Self {
+        let mut digits = vec![0; k + 1];
+        digits[k] = 1;
+        LargeInt::new(1, digits)
+    }
+
+    /// Constructs 2^k by setting a single bit in the binary limbs.
+    pub fn pow_of_two(k: u32) -> Self {
+        let mut limbs = vec![0u32; k as usize / 32 + 1];
+        limbs[k as usize / 32] = 1 << (k % 32);
+        LargeInt::from_binary_limbs(1, &limbs)
+    }
+
+    /// Returns true for positive values with exactly one set bit.
+    pub fn is_power_of_two(&self) -> bool {
+        self.sign == 1 && self.count_ones() == 1
+    }
+
+    /// Constructs a LargeInt representing two.
+    pub fn two() -> Self {
+        LargeInt::new(1, vec![2])
+    }
+
+    /// Constructs a LargeInt representing ten.
+    pub fn ten() -> Self {
+        LargeInt::new(1, vec![0, 1])
+    }
+
+}
+
+impl From<i64> for LargeInt {
+    fn from(n: i64) -> Self {
+        let sign = if n < 0 { -1 } else { 1 };
+        LargeInt::new(sign, LargeInt::from_u64(n.unsigned_abs()).digits)
+    }
+}
+
+impl PartialEq<i64> for LargeInt {
+    fn eq(&self, other: &i64) -> bool {
+        self.compare_signed(&LargeInt::from(*other)) == Ordering::Equal
+    }
+}
+
+impl PartialOrd<i64> for LargeInt {
+    fn partial_cmp(&self, other: &i64) -> Option<Ordering> {
+        Some(self.compare_signed(&LargeInt::from(*other)))
+    }
+}
+
+impl PartialEq<LargeInt> for i64 {
+    fn eq(&self, other: &LargeInt) -> bool {
+        other == self
+    }
+}
+
+impl PartialOrd<LargeInt> for i64 {
+    fn partial_cmp(&self, other: &LargeInt) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
+    }
+}
+
+/// Error returned when parsing a LargeInt from text. `position` is the byte
+/// offset of the offending character (or of the end of input when digits are
+/// missing), so a caller can point at it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseLargeIntError {
+    pub position: usize,
+    pub kind: ParseErrorKind,
+}
+
+/// What went wrong while parsing a LargeInt.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The input contained no digits.
+    Empty,
+    /// The input was a sign with no digits after it.
+    LoneSign,
+    /// A character was not a valid digit for the radix.
+    InvalidDigit(char),
+    /// The radix was outside 2 to 36.
+    InvalidRadix,
+    /// Scientific notation described a value with a fractional part.
+    NotAnInteger,
+    /// The scientific-notation exponent does not fit in a `usize`.
+    ExponentTooLarge,
+}
+
+impl ParseLargeIntError {
+    pub(crate) fn new(position: usize, kind: ParseErrorKind) -> Self {
+        ParseLargeIntError { position, kind }
+    }
+
+    /// The error for input with no digits before `end`: `LoneSign` when a
+    /// sign of `sign_len` bytes was all there was, `Empty` otherwise.
+    fn missing_digits(sign_len: usize, end: usize) -> Self {
+        let kind = if sign_len > 0 && end == sign_len {
+            ParseErrorKind::LoneSign
+        } else {
+            ParseErrorKind::Empty
+        };
+        ParseLargeIntError::new(end, kind)
+    }
+}
+
+impl fmt::Display for ParseLargeIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ParseErrorKind::Empty => write!(f, "No digits to parse")?,
+            ParseErrorKind::LoneSign => write!(f, "Sign is not followed by digits")?,
+            ParseErrorKind::InvalidDigit(digit) => {
+                write!(f, "Invalid digit '{}' for the given radix", digit)?
+            }
+            ParseErrorKind::InvalidRadix => write!(f, "Radix must be between 2 and 36")?,
+            ParseErrorKind::NotAnInteger => write!(f, "Value is not an integer")?,
+            ParseErrorKind::ExponentTooLarge => write!(f, "Exponent is too large")?,
+        }
+        write!(f, " at position {}", self.position)
+    }
+}
+
+impl std::error::Error for ParseLargeIntError {}
+
+// The bitwise operators act on the binary representation of the absolute
+// values and always return a non-negative result; this is not two's complement.
+impl BitAnd for &LargeInt {
+    type Output = LargeInt;
+
+    fn bitand(self, other: &LargeInt) -> LargeInt {
+        self.combine_bits(other, |a, b| a & b)
+    }
+}
+
+impl BitOr for &LargeInt {
+    type Output = LargeInt;
+
+    fn bitor(self, other: &LargeInt) -> LargeInt {
+        self.combine_bits(other, |a, b| a | b)
+    }
+}
+
+impl BitXor for &LargeInt {
+    type Output = LargeInt;
+
+    fn bitxor(self, other: &LargeInt) -> LargeInt {
+        self.combine_bits(other, |a, b| a ^ b)
+    }
+}
+
+impl BitAnd for LargeInt {
+    type Output = LargeInt;
+
+    fn bitand(self, other: LargeInt) -> LargeInt {
+        &self & &other
+    }
+}
+
+impl BitOr for LargeInt {
+    type Output = LargeInt;
+
+    fn bitor(self, other: LargeInt) -> LargeInt {
+        &self | &other
+    }
+}
+
+impl BitXor for LargeInt {
+    type Output = LargeInt;
+
+    fn bitxor(self, other: LargeInt) -> LargeInt {
+        &self ^ &other
+    }
+}
+
+impl Add for LargeInt {
+    type Output = LargeInt;
+
+    fn add(self, other: LargeInt) -> LargeInt {
+        LargeInt::add(&self, &other)
+    }
+}
+
+// Method-call syntax on an owned value (`sum.add(&x)`) resolves to the `Add`
+// trait before the inherent `add(&self, ...)`, so this keeps those calls working.
+impl Add<&LargeInt> for LargeInt {
+    type Output = LargeInt;
+
+    fn add(self, other: &LargeInt) -> LargeInt {
+        LargeInt::add(&self, other)
+    }
+}
+
+impl Sub for LargeInt {
+    type Output = LargeInt;
+
+    fn sub(self, other: LargeInt) -> LargeInt {
+        self.subtract(&other)
+    }
+}
+
+impl Mul for LargeInt {
+    type Output = LargeInt;
+
+    fn mul(self, other: LargeInt) -> LargeInt {
+        crate::multiply(&self, &other)
+    }
+}
+
+/// Truncating division, like `i64`. Panics on division by zero.
+impl Div for LargeInt {
+    type Output = LargeInt;
+
+    fn div(self, other: LargeInt) -> LargeInt {
+        crate::divide_and_modulo(&self, &other).0
+    }
+}
+
+/// Remainder with the sign of the dividend, like `i64`. Panics on division by zero.
+impl Rem for LargeInt {
+    type Output = LargeInt;
+
+    fn rem(self, other: LargeInt) -> LargeInt {
+        crate::divide_and_modulo(&self, &other).1
+    }
+}
+
+// Lets LargeInt satisfy the `num` traits used as bounds by generic numeric code.
+impl Zero for LargeInt {
+    fn zero() -> Self {
+        LargeInt::zero()
+    }
+
+    fn is_zero(&self) -> bool {
+        LargeInt::is_zero(self)
+    }
+}
+
+impl One for LargeInt {
+    fn one() -> Self {
+        LargeInt::one()
+    }
+}
+
+impl Num for LargeInt {
+    type FromStrRadixErr = ParseLargeIntError;
+
+    fn from_str_radix(input: &str, radix: u32) -> Result<Self, ParseLargeIntError> {
+        LargeInt::from_str_radix(input, radix)
+    }
+}
+
+impl fmt::Display for LargeInt {
+    /// Converts the LargeInt back into a string.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut result = self
+            .digits
+            .iter()
+            .rev()
+            .map(|&d| char::from_digit(d as u32, 10).unwrap())
+            .collect::<String>();
+        if self.sign == -1 {
+            result.insert(0, '-');
+        }
+        write!(f, "{}", result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    #[test]
+    fn test_addition() {
+        let a = LargeInt::parse("123");
+        let b = LargeInt::parse("456");
+        assert_eq!(a.add(&b).to_string(), "579");
+
+        let a = LargeInt::parse("-123");
+        let b = LargeInt::parse("456");
+        assert_eq!(a.add(&b).to_string(), "333");
+
+        let a = LargeInt::parse("123");
+        let b = LargeInt::parse("-456");
+        assert_eq!(a.add(&b).to_string(), "-333");
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn test_parallel_addition_matches_sequential() {
+        let cases = [
+            ("0", "0"),
+            ("5", "5"),
+            ("999999999999999999999999999999", "1"),
+            ("1", "999999999999999999999999999999"),
+            ("999999999999999999999999999999", "999999999999999999999999999999"),
+            ("123456789000000000999999999999", "876543211"),
+            ("-99999999999999999999", "-1"),
+            ("-123456789123456789", "-987654321987654321987654321"),
+        ];
+        for (a, b) in cases {
+            let (a, b) = (LargeInt::parse(a), LargeInt::parse(b));
+            let expected = a.add_same_sign_seq(&b);
+            for chunk in [1, 2, 3, 7, 64] {
+                let sum = a.add_same_sign_parallel(&b, chunk);
+                assert_eq!(sum, expected, "{} + {} in chunks of {}", a, b, chunk);
+            }
+        }
+    }
+
+    #[test]
+    fn test_subtraction() {
+        let a = LargeInt::parse("456");
+        let b = LargeInt::parse("123");
+        assert_eq!(a.subtract(&b).to_string(), "333");
+
+        let a = LargeInt::parse("123");
+        let b = LargeInt::parse("456");
+        assert_eq!(a.subtract(&b).to_string(), "-333");
+
+        let a = LargeInt::parse("-123");
+        let b = LargeInt::parse("-456");
+        assert_eq!(a.subtract(&b).to_string(), "333");
+        assert_eq!(b.subtract(&b).to_string(), "0");
+    }
+
+    #[test]
+    fn test_large_numbers() {
+        let a = LargeInt::parse("12345678901234567890");
+        let b = LargeInt::parse("98765432109876543210");
+        assert_eq!(a.add(&b).to_string(), "111111111011111111100");
+    }
+
+    #[test]
+    fn test_bit_length() {
+        assert_eq!(LargeInt::zero().bit_length(), 0);
+        assert_eq!(LargeInt::one().bit_length(), 1);
+        assert_eq!(LargeInt::parse("255").bit_length(), 8);
+        assert_eq!(LargeInt::parse("-256").bit_length(), 9);
+        assert_eq!(LargeInt::parse("18446744073709551616").bit_length(), 65);
+    }
+
+    #[test]
+    fn test_small_constants() {
+        assert_eq!(LargeInt::small(7).to_string(), "7");
+        assert_eq!(LargeInt::small(0).to_string(), "0");
+        assert_eq!(LargeInt::small(255).to_string(), "255");
+        assert_eq!(LargeInt::two().to_string(), "2");
+        assert_eq!(LargeInt::ten().to_string(), "10");
+        assert_eq!(LargeInt::ten().to_string(), LargeInt::parse("10").to_string());
+    }
+
+    #[test]
+    fn test_mul_add_horner() {
+        // 3x^3 - 2x^2 + 5x - 7 evaluated at a large x.
+        let x = LargeInt::parse("100000000000000000003");
+        let coefficients = ["3", "-2", "5", "-7"].map(LargeInt::parse);
+        let horner = coefficients[1..]
+            .iter()
+            .fold(coefficients[0].clone(), |acc, c| acc.mul_add(&x, c));
+
+        let power = |k: &str| crate::exponentiate(&x, &LargeInt::parse(k));
+        let expanded = crate::multiply(&coefficients[0], &power("3"))
+            .add(&crate::multiply(&coefficients[1], &power("2")))
+            .add(&crate::multiply(&coefficients[2], &x))
+            .add(&coefficients[3]);
+        assert_eq!(horner.to_string(), expanded.to_string());
+    }
+
+    #[test]
+    fn test_count_ones() {
+        assert_eq!(LargeInt::zero().count_ones(), 0);
+        assert_eq!(LargeInt::parse("255").count_ones(), 8);
+        assert_eq!(LargeInt::parse("256").count_ones(), 1);
+        assert_eq!(LargeInt::parse("-7").count_ones(), 3);
+        assert_eq!(LargeInt::parse("340282366920938463463374607431768211455").count_ones(), 128);
+    }
+
+    #[test]
+    fn test_bitwise_operators() {
+        let a = LargeInt::parse("12");
+        let b = LargeInt::parse("10");
+        assert_eq!((&a & &b).to_string(), "8");
+        assert_eq!((&a | &b).to_string(), "14");
+        assert_eq!((&a ^ &b).to_string(), "6");
+        assert_eq!((a.clone() ^ a).to_string(), "0");
+
+        // Operands spanning several limbs: (2^64 + 5) with (2^32 + 3).
+        let a = LargeInt::parse("18446744073709551621");
+        let b = LargeInt::parse("4294967299");
+        assert_eq!((&a & &b).to_string(), "1");
+        assert_eq!((&a | &b).to_string(), "18446744078004518919");
+        assert_eq!((LargeInt::parse("-12") & LargeInt::parse("10")).to_string(), "8");
+    }
+
+    #[test]
+    fn test_from_str_radix() {
+        let parse = |s, radix| LargeInt::from_str_radix(s, radix).map(|n| n.to_string());
+        assert_eq!(parse("ff", 16), Ok("255".to_string()));
+        assert_eq!(parse("-1010", 2), Ok("-10".to_string()));
+        assert_eq!(parse("+777", 8), Ok("511".to_string()));
+        assert_eq!(parse("zz", 36), Ok("1295".to_string()));
+        assert_eq!(parse("123", 10), Ok("123".to_string()));
+        assert_eq!(parse("-0", 16), Ok("0".to_string()));
+        assert_eq!(parse("", 10), Err(ParseLargeIntError::new(0, ParseErrorKind::Empty)));
+        assert_eq!(parse("-", 10), Err(ParseLargeIntError::new(1, ParseErrorKind::LoneSign)));
+        assert_eq!(parse("12g", 16), Err(ParseLargeIntError::new(2, ParseErrorKind::InvalidDigit('g'))));
+        assert_eq!(parse("1", 37), Err(ParseLargeIntError::new(0, ParseErrorKind::InvalidRadix)));
+    }
+
+    #[test]
+    fn test_parse_error_positions() {
+        let err = LargeInt::from_str_radix("12x4", 10).unwrap_err();
+        assert_eq!(err.position, 2);
+        assert_eq!(err.kind, ParseErrorKind::InvalidDigit('x'));
+        assert_eq!(err.to_string(), "Invalid digit 'x' for the given radix at position 2");
+
+        let lone = LargeInt::from_str_radix("+", 10).unwrap_err();
+        assert_eq!(lone, ParseLargeIntError::new(1, ParseErrorKind::LoneSign));
+        assert_eq!(lone.to_string(), "Sign is not followed by digits at position 1");
+        assert_eq!(LargeInt::parse_auto("-").unwrap_err().kind, ParseErrorKind::LoneSign);
+        assert_eq!(
+            LargeInt::parse_auto("-0x").unwrap_err(),
+            ParseLargeIntError::new(3, ParseErrorKind::Empty)
+        );
+        assert_eq!(LargeInt::parse_scientific("-").unwrap_err().kind, ParseErrorKind::LoneSign);
+        assert_eq!(LargeInt::parse_scientific(".").unwrap_err().kind, ParseErrorKind::Empty);
+        assert_eq!(
+            LargeInt::parse_scientific("1.2005e2").unwrap_err(),
+            ParseLargeIntError::new(5, ParseErrorKind::NotAnInteger)
+        );
+        assert_eq!(
+            LargeInt::parse_scientific("-1.25e1").unwrap_err(),
+            ParseLargeIntError::new(4, ParseErrorKind::NotAnInteger)
+        );
+    }
+
+    #[test]
+    fn test_from_str_radix_power_of_two_matches_generic() {
+        let hex: String = (0..1000).map(|i| char::from_digit((i * 7 + 3) % 16, 16).unwrap()).collect();
+        let values: Vec<u8> = hex.chars().map(|c| c.to_digit(16).unwrap() as u8).collect();
+        let fast = LargeInt::from_str_radix(&hex, 16).unwrap();
+        let generic = LargeInt::from_radix_digits(&values, 16);
+        assert_eq!(fast.to_string(), generic.to_string());
+
+        for (s, radix) in [("1011011101", 2), ("3210123", 4), ("7654321", 8), ("vu98a", 32)] {
+            let values: Vec<u8> = s.chars().map(|c| c.to_digit(radix).unwrap() as u8).collect();
+            assert_eq!(
+                LargeInt::from_str_radix(s, radix).unwrap().to_string(),
+                LargeInt::from_radix_digits(&values, radix).to_string()
+            );
+        }
+    }
+
+    #[test]
+    #[ignore = "timing comparison; run with --ignored in release mode"]
+    fn test_from_str_radix_power_of_two_is_faster() {
+        let hex: String = (0..20_000).map(|i| char::from_digit((i * 7 + 3) % 16, 16).unwrap()).collect();
+        let values: Vec<u8> = hex.chars().map(|c| c.to_digit(16).unwrap() as u8).collect();
+
+        let start = Instant::now();
+        let fast = LargeInt::from_str_radix(&hex, 16).unwrap();
+        let fast_time = start.elapsed();
+        let start = Instant::now();
+        let generic = LargeInt::from_radix_digits(&values, 16);
+        let generic_time = start.elapsed();
+
+        assert_eq!(fast, generic);
+        assert!(fast_time * 4 < generic_time, "{:?} vs {:?}", fast_time, generic_time);
+    }
+
+    #[test]
+    fn test_to_english_words() {
+        let words = |s| LargeInt::parse(s).to_english_words();
+        assert_eq!(words("0"), "zero");
+        assert_eq!(words("1234"), "one thousand two hundred thirty-four");
+        assert_eq!(words("1000000"), "one million");
+        assert_eq!(words("-40015"), "negative forty thousand fifteen");
+        assert_eq!(words("2000000000000017"), "two quadrillion seventeen");
+        assert_eq!(
+            words("1234567890123456789012345678901234567"),
+            "one two three four five six seven eight nine zero one two three four five six \
+             seven eight nine zero one two three four five six seven eight nine zero one two \
+             three four five six seven"
+        );
+    }
+
+    #[test]
+    fn test_pow10() {
+        assert_eq!(LargeInt::pow10(0).to_string(), "1");
+        assert_eq!(LargeInt::pow10(5).to_string(), "100000");
+        assert_eq!(LargeInt::pow10(30).digits.len(), 31);
+    }
+
+    #[test]
+    fn test_rem_small() {
+        let numbers = ["0", "12345678901234567890123456789", "-98765432109876543210", "18446744073709551615"];
+        for s in numbers {
+            let n = LargeInt::parse(s);
+            let magnitude = LargeInt::new(1, n.digits.clone());
+            for m in [2u64, 3, 7, 97, 65537, 4294967291, u64::MAX] {
+                let expected = crate::divide_and_modulo(&magnitude, &LargeInt::parse(&m.to_string())).1;
+                assert_eq!(n.rem_small(m).to_string(), expected.to_string());
+            }
+        }
+    }
+
+    #[test]
+    fn test_accessors() {
+        let n = LargeInt::parse("-1203");
+        assert_eq!(n.sign(), -1);
+        assert_eq!(n.decimal_digits(), &[3, 0, 2, 1]);
+
+        let zero = LargeInt::new(-1, vec![0, 0]);
+        assert_eq!(zero.sign(), 1);
+        assert_eq!(zero.decimal_digits(), &[0]);
+        assert_eq!(n.num_digits(), 4);
+        assert_eq!(zero.num_digits(), 1);
+    }
+
+    #[test]
+    fn test_compare_with_i64() {
+        assert!(LargeInt::parse("5") > 3i64);
+        assert!(LargeInt::zero() == 0i64);
+        assert!(LargeInt::one() == 1i64);
+        assert!(LargeInt::parse("-5") < -3i64);
+        assert!(LargeInt::parse("-5") < 0i64);
+        assert!(LargeInt::parse("100000000000000000000") > i64::MAX);
+        assert!(LargeInt::parse("-9223372036854775808") == i64::MIN);
+        assert!(3i64 < LargeInt::parse("5"));
+        assert!(0i64 == LargeInt::zero());
+        assert!(7i64 >= LargeInt::parse("-70"));
+    }
+
+    #[test]
+    fn test_output_formatters() {
+        assert_eq!(LargeInt::parse("255").to_str_radix(16), "ff");
+        assert_eq!(LargeInt::parse("-10").to_str_radix(2), "-1010");
+        assert_eq!(LargeInt::zero().to_str_radix(36), "0");
+        assert_eq!(LargeInt::parse("1234567").to_grouped_string(), "1,234,567");
+        assert_eq!(LargeInt::parse("-123").to_grouped_string(), "-123");
+        assert_eq!(LargeInt::parse("1500").to_scientific_string(), "1.5e3");
+        assert_eq!(LargeInt::parse("-7").to_scientific_string(), "-7e0");
+        assert_eq!(LargeInt::zero().to_scientific_string(), "0e0");
+    }
+
+    #[test]
+    fn test_from_str_radix_validates_digits() {
+        assert_eq!(
+            LargeInt::from_str_radix("89", 8).unwrap_err(),
+            ParseLargeIntError::new(0, ParseErrorKind::InvalidDigit('8'))
+        );
+        assert_eq!(
+            LargeInt::from_str_radix("-17a9", 8).unwrap_err(),
+            ParseLargeIntError::new(3, ParseErrorKind::InvalidDigit('a'))
+        );
+        assert_eq!(LargeInt::from_str_radix("89", 10).unwrap().to_string(), "89");
+    }
+
+    #[test]
+    fn test_small_predicates() {
+        assert!(LargeInt::one().is_one());
+        assert!(!LargeInt::parse("-1").is_one());
+        assert!(!LargeInt::parse("10").is_one());
+        assert_eq!(LargeInt::parse("255").as_u8(), Some(255));
+        assert_eq!(LargeInt::parse("256").as_u8(), None);
+        assert_eq!(LargeInt::parse("-3").as_u8(), None);
+        assert!(LargeInt::zero().fits_in_u8());
+        assert!(!LargeInt::parse("1000").fits_in_u8());
+    }
+
+    #[test]
+    fn test_to_usize() {
+        assert_eq!(LargeInt::parse("42").to_usize(), Some(42));
+        assert_eq!(LargeInt::zero().to_usize(), Some(0));
+        assert_eq!(LargeInt::parse(&usize::MAX.to_string()).to_usize(), Some(usize::MAX));
+        let above = LargeInt::parse(&format!("{}0", usize::MAX));
+        assert_eq!(above.to_usize(), None);
+        assert_eq!(LargeInt::parse("-5").to_usize(), None);
+    }
+
+    #[test]
+    fn test_parse_scientific() {
+        let parse = |s: &str| LargeInt::parse_scientific(s).map(|n| n.to_string());
+        assert_eq!(parse("1.5e10"), Ok("15000000000".to_string()));
+        assert_eq!(parse("2e3"), Ok("2000".to_string()));
+        assert_eq!(parse("-2E+3"), Ok("-2000".to_string()));
+        assert_eq!(parse("1.50e1"), Ok("15".to_string()));
+        assert_eq!(parse("42"), Ok("42".to_string()));
+        assert_eq!(parse("0.0e0"), Ok("0".to_string()));
+
+        assert_eq!(parse("1e-3"), Err(ParseLargeIntError::new(2, ParseErrorKind::NotAnInteger)));
+        assert_eq!(parse("1.25e1"), Err(ParseLargeIntError::new(3, ParseErrorKind::NotAnInteger)));
+        assert_eq!(parse("e5"), Err(ParseLargeIntError::new(0, ParseErrorKind::Empty)));
+        assert_eq!(parse("3e"), Err(ParseLargeIntError::new(2, ParseErrorKind::Empty)));
+        assert_eq!(parse("1.2.3"), Err(ParseLargeIntError::new(3, ParseErrorKind::InvalidDigit('.'))));
+        assert_eq!(parse("-1e2x"), Err(ParseLargeIntError::new(4, ParseErrorKind::InvalidDigit('x'))));
+        assert_eq!(
+            parse("1e99999999999999999999"),
+            Err(ParseLargeIntError::new(2, ParseErrorKind::ExponentTooLarge))
+        );
+    }
+
+    #[test]
+    fn test_push_decimal_digit() {
+        let mut n = LargeInt::zero();
+        for d in [1, 2, 3, 4] {
+            n.push_decimal_digit(d);
+        }
+        assert_eq!(n.to_string(), LargeInt::parse("1234").to_string());
+
+        let mut n = LargeInt::zero();
+        n.push_decimal_digit(0);
+        n.push_decimal_digit(7);
+        assert_eq!(n.to_string(), "7");
+
+        let mut n = LargeInt::parse("-12");
+        n.push_decimal_digit(0);
+        assert_eq!(n.to_string(), "-120");
+        n.push_decimal_digit(3);
+        assert_eq!(n.to_string(), "-1197");
+
+        let mut n = LargeInt::parse("-1");
+        n.push_decimal_digit(9);
+        assert_eq!(n.to_string(), "-1");
+    }
+
+    #[test]
+    fn test_push_decimal_digits() {
+        let push = |start: &str, ds: &[u8]| {
+            let mut n = LargeInt::parse(start);
+            n.push_decimal_digits(ds);
+            n.to_string()
+        };
+        assert_eq!(push("0", &[0, 0, 4, 2]), "42");
+        assert_eq!(push("17", &[]), "17");
+        assert_eq!(push("-5", &[0, 0]), "-500");
+        assert_eq!(push("-1", &[1, 2, 3]), "-877");
+        assert_eq!(push("-1", &[9, 9]), "-1");
+        assert_eq!(push("-1000", &[9, 9, 9]), "-999001");
+
+        let mut seed = 21u64;
+        let text: String = (0..5000)
+            .map(|_| {
+                seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                char::from(b'1' + (seed >> 60) as u8 % 9)
+            })
+            .collect();
+        let mut streamed = LargeInt::zero();
+        for chunk in text.as_bytes().chunks(512) {
+            streamed.push_decimal_digits(&chunk.iter().map(|b| b - b'0').collect::<Vec<_>>());
+        }
+        assert_eq!(streamed, LargeInt::parse(&text));
+    }
+
+    #[test]
+    #[should_panic(expected = "Decimal digit must be below 10!")]
+    fn test_push_decimal_digit_rejects_large() {
+        LargeInt::zero().push_decimal_digit(10);
+    }
+
+    #[test]
+    fn test_digit_access() {
+        let mut n = LargeInt::parse("-9051");
+        assert_eq!((n.digit_at(0), n.digit_at(1), n.digit_at(3)), (1, 5, 9));
+        assert_eq!(n.digit_at(100), 0);
+
+        n.set_digit(1, 7);
+        assert_eq!(n.to_string(), "-9071");
+        n.set_digit(6, 2);
+        assert_eq!(n.to_string(), "-2009071");
+
+        n.set_digit(6, 0);
+        assert_eq!(n.to_string(), "-9071");
+        assert_eq!(n.num_digits(), 4);
+
+        let mut n = LargeInt::parse("-5");
+        n.set_digit(0, 0);
+        assert_eq!((n.to_string(), n.sign()), ("0".to_string(), 1));
+    }
+
+    #[test]
+    fn test_bits_le_round_trip() {
+        assert!(LargeInt::zero().to_bits_le().is_empty());
+        assert_eq!(LargeInt::parse("6").to_bits_le(), vec![false, true, true]);
+        assert_eq!(LargeInt::from_bits_le(-1, &[true, false, true]).to_string(), "-5");
+        assert_eq!(LargeInt::from_bits_le(-1, &[]).to_string(), "0");
+
+        for s in ["0", "1", "4294967295", "4294967296", "-123456789012345678901234567890"] {
+            let n = LargeInt::parse(s);
+            let bits = n.to_bits_le();
+            assert_eq!(bits.len() as u64, n.bit_length());
+            assert_eq!(LargeInt::from_bits_le(n.sign(), &bits).to_string(), s);
+        }
+    }
+
+    #[test]
+    fn test_to_digit_groups() {
+        let n = LargeInt::parse("123456789");
+        assert_eq!(n.to_digit_groups(3), vec![789, 456, 123]);
+        assert_eq!(n.to_digit_groups(4), vec![6789, 2345, 1]);
+        assert_eq!(n.to_digit_groups(1), vec![9, 8, 7, 6, 5, 4, 3, 2, 1]);
+        assert_eq!(n.to_digit_groups(19), vec![123456789]);
+        assert_eq!(LargeInt::parse("-1000000").to_digit_groups(3), vec![0, 0, 1]);
+        assert_eq!(LargeInt::zero().to_digit_groups(5), vec![0]);
+
+        let max = LargeInt::parse("99999999999999999999999999999999999999");
+        assert_eq!(max.to_digit_groups(19), vec![9999999999999999999, 9999999999999999999]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Digit group size must be between 1 and 19!")]
+    fn test_to_digit_groups_too_wide() {
+        LargeInt::one().to_digit_groups(20);
+    }
+
+    #[test]
+    fn test_powers_of_two() {
+        assert_eq!(LargeInt::pow_of_two(0).to_string(), "1");
+        assert_eq!(LargeInt::pow_of_two(10).to_string(), "1024");
+        assert_eq!(LargeInt::pow_of_two(64).to_string(), "18446744073709551616");
+        assert!(LargeInt::parse("1024").is_power_of_two());
+        assert!(LargeInt::pow_of_two(200).is_power_of_two());
+        assert!(!LargeInt::parse("1000").is_power_of_two());
+        assert!(!LargeInt::zero().is_power_of_two());
+        assert!(!LargeInt::parse("-8").is_power_of_two());
+    }
+
+    #[test]
+    fn test_rotate_digits() {
+        let n = LargeInt::parse("12345");
+        assert_eq!(n.rotate_digits_left(2).to_string(), "34512");
+        assert_eq!(n.rotate_digits_right(2).to_string(), "45123");
+        assert_eq!(n.rotate_digits_left(7).to_string(), "34512");
+        assert_eq!(n.rotate_digits_right(5).to_string(), "12345");
+        assert_eq!(LargeInt::parse("-1002").rotate_digits_left(1).to_string(), "-21");
+        assert_eq!(LargeInt::zero().rotate_digits_right(3).to_string(), "0");
+    }
+
+    #[test]
+    fn test_from_ascii_bytes() {
+        assert_eq!(LargeInt::from_ascii_bytes(b"-12345").unwrap().to_string(), "-12345");
+        assert_eq!(LargeInt::from_ascii_bytes(b"+007").unwrap().to_string(), "7");
+        assert_eq!(
+            LargeInt::from_ascii_bytes(b"12x").unwrap_err(),
+            ParseLargeIntError::new(2, ParseErrorKind::InvalidDigit('x'))
+        );
+        assert_eq!(
+            LargeInt::from_ascii_bytes(b"-1y2z").unwrap_err(),
+            ParseLargeIntError::new(2, ParseErrorKind::InvalidDigit('y'))
+        );
+        let lone = ParseLargeIntError::new(1, ParseErrorKind::LoneSign);
+        assert_eq!(LargeInt::from_ascii_bytes(b"-").unwrap_err(), lone);
+        let empty = ParseLargeIntError::new(0, ParseErrorKind::Empty);
+        assert_eq!(LargeInt::from_ascii_bytes(b"").unwrap_err(), empty);
+    }
+
+    #[test]
+    fn test_sub_if_ge() {
+        let m = LargeInt::parse("97");
+        assert_eq!(LargeInt::parse("150").sub_if_ge(&m).to_string(), "53");
+        assert_eq!(LargeInt::parse("97").sub_if_ge(&m).to_string(), "0");
+        assert_eq!(LargeInt::parse("96").sub_if_ge(&m).to_string(), "96");
+        assert_eq!(LargeInt::parse("-100").sub_if_ge(&LargeInt::parse("-1")).to_string(), "-99");
+    }
+
+    #[test]
+    fn test_parse_auto() {
+        let parse = |s: &str| LargeInt::parse_auto(s).map(|n| n.to_string());
+        assert_eq!(parse("0xff"), Ok("255".to_string()));
+        assert_eq!(parse("0b1010"), Ok("10".to_string()));
+        assert_eq!(parse("-0o17"), Ok("-15".to_string()));
+        assert_eq!(parse("+0XFF"), Ok("255".to_string()));
+        assert_eq!(parse("12345678901234567890"), Ok("12345678901234567890".to_string()));
+        assert_eq!(parse("0"), Ok("0".to_string()));
+        assert_eq!(parse("0x"), Err(ParseLargeIntError::new(2, ParseErrorKind::Empty)));
+        assert_eq!(parse("-0b102"), Err(ParseLargeIntError::new(5, ParseErrorKind::InvalidDigit('2'))));
+        assert_eq!(parse("0x-1"), Err(ParseLargeIntError::new(2, ParseErrorKind::InvalidDigit('-'))));
+    }
+
+    #[test]
+    fn test_luhn() {
+        let card = LargeInt::parse("79927398713");
+        assert!(card.is_valid_luhn());
+        assert!(LargeInt::parse("4539578763621486").is_valid_luhn());
+        assert!(!LargeInt::parse("79927398714").is_valid_luhn());
+        assert!(!LargeInt::parse("79927398173").is_valid_luhn());
+
+        let payload = LargeInt::parse("7992739871");
+        assert_eq!(payload.luhn_check_digit(), 3);
+        let mut with_check = payload.clone();
+        with_check.push_decimal_digit(payload.luhn_check_digit());
+        assert!(with_check.is_valid_luhn());
+    }
+
+    #[test]
+    fn test_num_traits() {
+        fn sum_plus_one<T: Zero + One + Add<Output = T> + Clone>(values: &[T]) -> T {
+            values.iter().cloned().fold(T::zero(), |acc, x| acc + x) + T::one()
+        }
+
+        assert_eq!(sum_plus_one(&[1i64, 2, 3]), 7);
+        let big = [LargeInt::parse("99999999999999999999"), LargeInt::parse("1")];
+        assert_eq!(sum_plus_one(&big).to_string(), "100000000000000000001");
+        assert_eq!(sum_plus_one::<LargeInt>(&[]).to_string(), "1");
+        assert!(<LargeInt as Zero>::is_zero(&LargeInt::parse("-0")));
+        assert_eq!((LargeInt::parse("12") * LargeInt::parse("-3")).to_string(), "-36");
+    }
+
+    #[test]
+    fn test_num_trait() {
+        assert_eq!(<LargeInt as Num>::from_str_radix("ff", 16), Ok(LargeInt::parse("255")));
+        assert_eq!(
+            <LargeInt as Num>::from_str_radix("fg", 16),
+            Err(ParseLargeIntError::new(1, ParseErrorKind::InvalidDigit('g')))
+        );
+
+        fn parse_then_divmod<T: Num + Clone>(input: &str, by: T) -> Option<(T, T)> {
+            let value = T::from_str_radix(input, 10).ok()?;
+            Some((value.clone() / by.clone(), value % by))
+        }
+        assert_eq!(parse_then_divmod("-17", 5i64), Some((-3, -2)));
+        assert_eq!(
+            parse_then_divmod("-17", LargeInt::from(5)),
+            Some((LargeInt::from(-3), LargeInt::from(-2)))
+        );
+        assert_eq!(LargeInt::from(7) - LargeInt::from(10), LargeInt::from(-3));
+    }
+
+    #[test]
+    fn test_from_decimal_digits() {
+        let n = LargeInt::from_decimal_digits(vec![4, 3, 2, 1, 0, 0]);
+        assert_eq!(n, LargeInt::parse("1234"));
+        assert_eq!(n.decimal_digits(), &[4, 3, 2, 1]);
+        assert_eq!(LargeInt::from_decimal_digits(Vec::new()), LargeInt::zero());
+
+        // The binary-limb form used by the bit operations holds the same values.
+        for s in ["0", "1", "4294967296", "98765432109876543210987654321"] {
+            let digits: Vec<u8> = s.bytes().rev().map(|b| b - b'0').collect();
+            let n = LargeInt::from_decimal_digits(digits);
+            assert_eq!(LargeInt::from_binary_limbs(1, &n.to_binary_limbs()), n);
+            assert_eq!(n.to_string(), s);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Decimal digit must be below 10!")]
+    fn test_from_decimal_digits_rejects_large() {
+        LargeInt::from_decimal_digits(vec![1, 10]);
+    }
+
+    #[test]
+    fn test_u64_conversions() {
+        assert_eq!(LargeInt::parse("18446744073709551615").to_u64(), Some(u64::MAX));
+        assert!(!LargeInt::parse("18446744073709551616").fits_in_u64());
+        assert!(!LargeInt::parse("-1").fits_in_u64());
+        assert_eq!(LargeInt::from_u64(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(LargeInt::from_u64(0), LargeInt::zero());
+    }
+
+    #[test]
+    fn test_u64_and_u128_boundaries() {
+        let max_u64 = LargeInt::from_u64(u64::MAX);
+        let above_u64 = LargeInt::from_u64(u64::MAX).add(&LargeInt::one());
+        assert_eq!(max_u64.to_u64(), Some(u64::MAX));
+        assert_eq!(max_u64.subtract(&LargeInt::one()).to_u64(), Some(u64::MAX - 1));
+        assert_eq!(above_u64.to_u64(), None);
+        assert_eq!(above_u64.to_u128(), Some(u64::MAX as u128 + 1));
+        assert_eq!(LargeInt::zero().to_u128(), Some(0));
+        assert_eq!(LargeInt::parse("-0").to_u64(), Some(0));
+        assert_eq!(LargeInt::parse("-1").to_u128(), None);
+
+        let max_u128 = LargeInt::parse(&u128::MAX.to_string());
+        assert_eq!(max_u128.to_u128(), Some(u128::MAX));
+        assert_eq!(max_u128.to_u64(), None);
+        assert_eq!(max_u128.add(&LargeInt::one()).to_u128(), None);
+        assert_eq!(LargeInt::pow10(39).to_u128(), None);
+        assert_eq!(LargeInt::parse("000000000000000000000000000000000000000042").to_u128(), Some(42));
+    }
+
+    #[test]
+    fn test_canonicalize() {
+        let mut negative_zero = LargeInt { sign: -1, digits: vec![0, 0] };
+        negative_zero.canonicalize();
+        assert_eq!(negative_zero.to_string(), "0");
+        assert_eq!(negative_zero, LargeInt::zero());
+
+        let mut padded = LargeInt { sign: -1, digits: vec![3, 2, 0, 0] };
+        padded.canonicalize();
+        assert_eq!(padded, LargeInt::parse("-23"));
+        assert_eq!(LargeInt::new(-1, vec![]), LargeInt::zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "Sign must be 1 or -1!")]
+    fn test_new_rejects_bad_sign() {
+        LargeInt::new(0, vec![1]);
     }
 }
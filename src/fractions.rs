@@ -1,8 +1,97 @@
 // Implemantation for handling fractions/// A module for representing and working with fractions in Rust.
 /// It supports mixed fractions, proper/improper fractions, and handling of negative exponents.
+use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::fmt;
 use std::ops::{Add, Div, Mul, Sub};
+use std::str::FromStr;
 use num::integer::gcd;
+use crate::{divide, divide_and_modulo, exponentiate_limited, is_probable_prime, multiply, LargeInt};
+
+/// Errors produced by the fallible Fraction operations.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FractionError {
+    /// The denominator was zero.
+    ZeroDenominator,
+    /// The reduced result does not fit in an `i64`.
+    Overflow,
+    /// Paired slices, such as values and their weights, differ in length.
+    LengthMismatch,
+}
+
+impl fmt::Display for FractionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FractionError::ZeroDenominator => write!(f, "Denominator cannot be zero"),
+            FractionError::Overflow => write!(f, "Fraction does not fit in 64-bit integers"),
+            FractionError::LengthMismatch => write!(f, "Slices must have the same length"),
+        }
+    }
+}
+
+impl std::error::Error for FractionError {}
+
+/// Error returned by `Fraction::checked_from_str` and `Fraction::from_decimal_str`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseFractionError {
+    pub kind: ParseFractionErrorKind,
+}
+
+/// What was wrong with the text given to `Fraction::checked_from_str` or
+/// `Fraction::from_decimal_str`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseFractionErrorKind {
+    /// The input was empty or only whitespace.
+    Empty,
+    /// A numerator, denominator or whole part was not an integer within
+    /// `±i64::MAX`, the fractional part of a mixed number was not a proper
+    /// positive fraction, a mixed number overflowed, or a decimal was malformed
+    /// or did not fit in `i64` parts.
+    InvalidNumber,
+    /// More than one `/` appeared, as in `1/2/3`.
+    TooManySlashes,
+    /// The denominator was zero.
+    ZeroDenominator,
+    /// A non-ASCII character that is not a known vulgar fraction such as `½`.
+    UnknownSymbol(char),
+}
+
+impl ParseFractionError {
+    fn new(kind: ParseFractionErrorKind) -> Self {
+        ParseFractionError { kind }
+    }
+}
+
+impl fmt::Display for ParseFractionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ParseFractionErrorKind::Empty => write!(f, "No fraction to parse"),
+            ParseFractionErrorKind::InvalidNumber => write!(f, "Numbers must be whole 64-bit integers"),
+            ParseFractionErrorKind::TooManySlashes => write!(f, "A fraction has at most one '/'"),
+            ParseFractionErrorKind::ZeroDenominator => write!(f, "Denominator cannot be zero"),
+            ParseFractionErrorKind::UnknownSymbol(symbol) => {
+                write!(f, "Unknown fraction symbol '{}'", symbol)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseFractionError {}
+
+/// How `Fraction::round_with` resolves a non-integral value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Nearest integer, ties away from zero.
+    HalfUp,
+    /// Nearest integer, ties to the even neighbour (banker's rounding).
+    HalfEven,
+    /// Largest integer not above the value.
+    Floor,
+    /// Smallest integer not below the value.
+    Ceil,
+    /// Drops the fractional part.
+    TowardZero,
+}
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Fraction {
@@ -26,6 +115,181 @@ impl Fraction {
         }
     }
 
+    /// Like `new`, but also returns the common factor that was divided out,
+    /// or `None` if the parts were already in lowest terms.
+    /// Panics on a zero denominator.
+    pub fn from_parts_verbose(numerator: i64, denominator: i64) -> (Self, Option<i64>) {
+        let fraction = Fraction::new(numerator, denominator);
+        let divisor = gcd(numerator.abs(), denominator.abs());
+        (fraction, if divisor == 1 { None } else { Some(divisor) })
+    }
+
+    /// Creates a Fraction from big integer parts, reducing with the LargeInt gcd.
+    /// Errors instead of panicking on a zero denominator or when the reduced
+    /// parts do not fit in an `i64`.
+    pub fn from_ratio(numerator: LargeInt, denominator: LargeInt) -> Result<Self, FractionError> {
+        if denominator.is_zero() {
+            return Err(FractionError::ZeroDenominator);
+        }
+
+        let divisor = crate::gcd(&numerator, &denominator);
+        let sign = numerator.sign() * denominator.sign();
+        let numerator = divide_and_modulo(&numerator, &divisor).0;
+        let denominator = divide_and_modulo(&denominator, &divisor).0;
+
+        let magnitude = |n: &LargeInt| {
+            LargeInt::new(1, n.decimal_digits().to_vec())
+                .to_u64()
+                .and_then(|m| i64::try_from(m).ok())
+                .ok_or(FractionError::Overflow)
+        };
+
+        Ok(Fraction {
+            numerator: sign as i64 * magnitude(&numerator)?,
+            denominator: magnitude(&denominator)?,
+        })
+    }
+
+    /// Parses an exact decimal such as `"-1.25"` into a reduced Fraction (`-5/4`).
+    /// Errors on malformed input or when the value does not fit in `i64` parts.
+    pub fn from_decimal_str(input: &str) -> Result<Fraction, ParseFractionError> {
+        let input = input.trim();
+        if input.is_empty() {
+            return Err(ParseFractionError::new(ParseFractionErrorKind::Empty));
+        }
+        let invalid = || ParseFractionError::new(ParseFractionErrorKind::InvalidNumber);
+        let (sign, body) = match input.strip_prefix('-') {
+            Some(rest) => (-1, rest),
+            None => (1, input.strip_prefix('+').unwrap_or(input)),
+        };
+        let (whole, fraction) = body.split_once('.').unwrap_or((body, ""));
+        let all_digits = whole.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit());
+        if (whole.is_empty() && fraction.is_empty()) || !all_digits {
+            return Err(invalid());
+        }
+
+        let digits = format!("{}{}", whole, fraction);
+        let numerator = digits.parse::<i128>().map_err(|_| invalid())?;
+        let denominator = u32::try_from(fraction.len())
+            .ok()
+            .and_then(|places| 10i128.checked_pow(places))
+            .ok_or_else(invalid)?;
+        reduce_wide(sign * numerator, denominator).map_err(|_| invalid())
+    }
+
+    /// Finds the best rational approximation of `x` whose denominator is at most
+    /// `max_denominator`, walking its continued fraction and finishing with the
+    /// closer of the last convergent and semiconvergent. The walk also stops
+    /// before a convergent whose numerator would leave `i64`, so for large |x|
+    /// the result may have a denominator well below `max_denominator`.
+    /// Panics if `x` is not finite or too large for `i64`, or if `max_denominator < 1`.
+    pub fn approx_from_f64(x: f64, max_denominator: i64) -> Fraction {
+        if !x.is_finite() || x.abs() >= i64::MAX as f64 {
+            panic!("Cannot approximate a non-finite or out-of-range value!");
+        }
+        if max_denominator < 1 {
+            panic!("Maximum denominator must be at least one!");
+        }
+
+        let target = x.abs();
+        // The recurrence runs in i128, where `a * p1 + p0` cannot overflow for
+        // any `a` below 2^63; terms beyond that bound end the walk.
+        let (mut p0, mut q0, mut p1, mut q1) = (0i128, 1i128, 1i128, 0i128);
+        let mut value = target;
+        loop {
+            let a = value.floor();
+            if a >= i64::MAX as f64 {
+                break;
+            }
+            let a = a as i128;
+            let (p2, q2) = (p0 + a * p1, q0 + a * q1);
+            if q2 > max_denominator as i128 || p2 > i64::MAX as i128 {
+                break;
+            }
+            (p0, q0, p1, q1) = (p1, q1, p2, q2);
+
+            let remainder = value - a as f64;
+            if remainder == 0.0 {
+                break;
+            }
+            value = 1.0 / remainder;
+        }
+
+        // The first term is |x| < 2^63 over 1, so q1 >= 1 here. Both bounds keep
+        // the semiconvergent inside i64.
+        let k = ((max_denominator as i128 - q0) / q1).min((i64::MAX as i128 - p0) / p1.max(1));
+        let (p_semi, q_semi) = (p0 + k * p1, q0 + k * q1);
+        let error = |p: i128, q: i128| (p as f64 / q as f64 - target).abs();
+        let (p, q) = if error(p1, q1) <= error(p_semi, q_semi) {
+            (p1, q1)
+        } else {
+            (p_semi, q_semi)
+        };
+
+        Fraction::new(x.signum() as i64 * p as i64, q as i64)
+    }
+
+    /// Returns the continued-fraction coefficients `[a0; a1, a2, ...]`.
+    /// `a0` is the floor of the value, so it is negative for negative fractions.
+    pub fn continued_fraction(&self) -> Vec<i64> {
+        let (mut numerator, mut denominator) = (self.numerator as i128, self.denominator as i128);
+        let mut coefficients = Vec::new();
+        while denominator != 0 {
+            coefficients.push(numerator.div_euclid(denominator) as i64);
+            (numerator, denominator) = (denominator, numerator.rem_euclid(denominator));
+        }
+        coefficients
+    }
+
+    /// Returns the convergents of the continued fraction, from `a0/1` up to the
+    /// fraction itself, using `h_n = a_n * h_(n-1) + h_(n-2)` (and likewise for `k_n`).
+    pub fn convergents(&self) -> Vec<Fraction> {
+        let (mut h0, mut k0, mut h1, mut k1) = (0i128, 1i128, 1i128, 0i128);
+        self.continued_fraction()
+            .into_iter()
+            .map(|a| {
+                let a = a as i128;
+                (h0, k0, h1, k1) = (h1, k1, a * h1 + h0, a * k1 + k0);
+                // Convergents never exceed the fraction's own parts, so they fit in i64.
+                Fraction { numerator: h1 as i64, denominator: k1 as i64 }
+            })
+            .collect()
+    }
+
+    /// Reduces every fraction in the slice in place.
+    /// Useful after building fractions directly from their public fields.
+    pub fn reduce_all(fractions: &mut [Fraction]) {
+        for fraction in fractions.iter_mut() {
+            *fraction = Fraction::new(fraction.numerator, fraction.denominator);
+        }
+    }
+
+    /// Returns the exact, reduced sum of all fractions in the slice.
+    pub fn sum_all(fractions: &[Fraction]) -> Fraction {
+        fractions
+            .iter()
+            .cloned()
+            .fold(Fraction::new(0, 1), |acc, fraction| acc + fraction)
+    }
+
+    /// Returns the exact weighted mean `Σ(v_i * w_i) / Σ(w_i)`.
+    /// Fails with `LengthMismatch` when the slices differ in length,
+    /// `ZeroDenominator` when the weights sum to zero (including no weights),
+    /// and `Overflow` if an intermediate sum does not fit in `i64`.
+    pub fn weighted_mean(values: &[Fraction], weights: &[Fraction]) -> Result<Fraction, FractionError> {
+        if values.len() != weights.len() {
+            return Err(FractionError::LengthMismatch);
+        }
+
+        let mut weighted_sum = Fraction::new(0, 1);
+        let mut total_weight = Fraction::new(0, 1);
+        for (value, weight) in values.iter().zip(weights) {
+            weighted_sum = weighted_sum.checked_add(&value.checked_mul(weight)?)?;
+            total_weight = total_weight.checked_add(weight)?;
+        }
+        weighted_sum.try_div(&total_weight)
+    }
+
     /// Converts the fraction into a mixed fraction form (whole part and remaining fraction).
     pub fn to_mixed(&self) -> (i64, Fraction) {
         let whole_part = self.numerator / self.denominator;
@@ -34,6 +298,32 @@ impl Fraction {
         (whole_part, Fraction::new(remainder, self.denominator))
     }
 
+    /// Like `to_mixed`, but computes the whole part and remainder with the
+    /// big-integer `divide_and_modulo`, so the whole part is a LargeInt.
+    /// The remainder takes the sign of the fraction, as in `to_mixed`.
+    pub fn to_mixed_big(&self) -> (LargeInt, Fraction) {
+        let whole = divide_and_modulo(&LargeInt::from(self.numerator), &LargeInt::from(self.denominator)).0;
+        // The truncated i64 remainder matches the big one and cannot overflow.
+        (whole, Fraction::new(self.numerator % self.denominator, self.denominator))
+    }
+
+    /// Renders the fraction in mixed form, e.g. `"2 1/3"`, `"-2 1/3"` or `"3/4"`.
+    /// Only the whole part carries the sign, and whole numbers (zero included)
+    /// have no fractional part. This is the default `Display` output and
+    /// `FromStr` parses it back losslessly.
+    pub fn to_mixed_string(&self) -> String {
+        if self.is_proper() && self.numerator != 0 {
+            format!("{}/{}", self.numerator, self.denominator)
+        } else {
+            let (whole, remainder) = self.to_mixed();
+            if remainder.numerator == 0 {
+                format!("{}", whole)
+            } else {
+                format!("{} {}/{}", whole, remainder.numerator.abs(), remainder.denominator)
+            }
+        }
+    }
+
     /// Calculates the reciprocal of the fraction.
     pub fn reciprocal(&self) -> Self {
         if self.numerator == 0 {
@@ -48,33 +338,709 @@ impl Fraction {
             return Fraction::new(1, 1);
         }
 
-        let base = if exp > 0 {
+        if exp > 0 {
             Fraction::new(self.numerator.pow(exp as u32), self.denominator.pow(exp as u32))
         } else {
             self.reciprocal().exponentiate(-exp)
+        }
+    }
+
+    /// Exponentiates the fraction to a LargeInt power using the big-int
+    /// `exponentiate_limited` on both parts; negative exponents use the reciprocal.
+    /// Since the parts are still `i64`, results that do not fit give
+    /// `FractionError::Overflow` (detected before the power is computed) instead
+    /// of an `i64::pow` overflow. A zero base with a negative exponent gives
+    /// `FractionError::ZeroDenominator`.
+    pub fn exponentiate_big(&self, exp: &LargeInt) -> Result<Fraction, FractionError> {
+        let (base_numerator, base_denominator) = if exp.sign() == -1 {
+            (self.denominator * self.numerator.signum(), self.numerator.abs())
+        } else {
+            (self.numerator, self.denominator)
+        };
+        if base_denominator == 0 {
+            return Err(FractionError::ZeroDenominator);
+        }
+
+        let magnitude = LargeInt::new(1, exp.decimal_digits().to_vec());
+        // An i64 has at most 19 decimal digits.
+        let power = |n: i64| {
+            exponentiate_limited(&LargeInt::from(n), &magnitude, 19).map_err(|_| FractionError::Overflow)
         };
+        Fraction::from_ratio(power(base_numerator)?, power(base_denominator)?)
+    }
+
+    /// Adds two fractions using `i128` intermediates.
+    /// Returns `FractionError::Overflow` if the reduced sum does not fit in `i64`.
+    pub fn checked_add(&self, other: &Fraction) -> Result<Fraction, FractionError> {
+        let numerator = self.numerator as i128 * other.denominator as i128
+            + other.numerator as i128 * self.denominator as i128;
+        let denominator = self.denominator as i128 * other.denominator as i128;
+        reduce_wide(numerator, denominator)
+    }
+
+    /// Multiplies two fractions using `i128` intermediates.
+    /// Returns `FractionError::Overflow` if the reduced product does not fit in `i64`.
+    pub fn checked_mul(&self, other: &Fraction) -> Result<Fraction, FractionError> {
+        let numerator = self.numerator as i128 * other.numerator as i128;
+        let denominator = self.denominator as i128 * other.denominator as i128;
+        reduce_wide(numerator, denominator)
+    }
+
+    /// Multiplies by an integer using an `i128` product reduced to lowest terms.
+    /// Panics if the result does not fit in `i64`.
+    pub fn mul_int(&self, n: i64) -> Fraction {
+        reduce_wide(self.numerator as i128 * n as i128, self.denominator as i128)
+            .expect("Product does not fit in 64-bit integers!")
+    }
+
+    /// Divides by an integer, cancelling against the numerator first.
+    /// Returns `FractionError::ZeroDenominator` when `n` is zero and
+    /// `FractionError::Overflow` if the denominator does not fit in `i64`.
+    pub fn div_int(&self, n: i64) -> Result<Fraction, FractionError> {
+        if n == 0 {
+            return Err(FractionError::ZeroDenominator);
+        }
+        let divisor = gcd(self.numerator, n) as i128;
+        let sign = n.signum() as i128;
+        reduce_wide(
+            self.numerator as i128 / divisor * sign,
+            self.denominator as i128 * (n as i128 / divisor).abs(),
+        )
+    }
+
+    /// Divides two fractions using `i128` intermediates.
+    /// Returns `FractionError::ZeroDenominator` when the divisor is zero and
+    /// `FractionError::Overflow` if the reduced quotient does not fit in `i64`.
+    pub fn try_div(&self, other: &Fraction) -> Result<Fraction, FractionError> {
+        if other.numerator == 0 {
+            return Err(FractionError::ZeroDenominator);
+        }
+        let sign = other.numerator.signum() as i128;
+        let numerator = self.numerator as i128 * other.denominator as i128 * sign;
+        let denominator = self.denominator as i128 * (other.numerator as i128).abs();
+        reduce_wide(numerator, denominator)
+    }
 
-        base
+    /// Divides two fractions, returning `None` when the divisor is zero
+    /// (or the quotient overflows). This is what the REPL's divide option uses.
+    pub fn checked_div(&self, other: Fraction) -> Option<Fraction> {
+        self.try_div(&other).ok()
+    }
+
+    /// Returns a wrapper that always displays the fraction as `num/den`.
+    pub fn display_improper(&self) -> ImproperDisplay<'_> {
+        ImproperDisplay(self)
+    }
+
+    /// Renders the fraction as a fixed-point decimal with `decimals` places,
+    /// rounding the last place half away from zero, e.g. `1/8` gives `"0.125"`.
+    pub fn to_decimal_string(&self, decimals: usize) -> String {
+        decimal_expansion(self.numerator as i128, self.denominator as i128, decimals)
+    }
+
+    /// Splits the decimal expansion into its non-repeating part and repeating block,
+    /// e.g. `1/6` gives `("0.1", Some("6"))`. Terminating fractions return `None`
+    /// for the block, and whole numbers have no decimal point.
+    pub fn to_repeating_decimal(&self) -> (String, Option<String>) {
+        let numerator = (self.numerator as i128).abs();
+        let denominator = self.denominator as i128;
+
+        let mut prefix = String::new();
+        if self.numerator < 0 {
+            prefix.push('-');
+        }
+        prefix.push_str(&(numerator / denominator).to_string());
+
+        let mut remainder = numerator % denominator;
+        if remainder == 0 {
+            return (prefix, None);
+        }
+        prefix.push('.');
+
+        // A remainder seen before means the digits from its first position repeat.
+        let mut seen = HashMap::new();
+        let mut digits = String::new();
+        while remainder != 0 {
+            if let Some(&start) = seen.get(&remainder) {
+                let repeating = digits.split_off(start);
+                prefix.push_str(&digits);
+                return (prefix, Some(repeating));
+            }
+            seen.insert(remainder, digits.len());
+            remainder *= 10;
+            digits.push((b'0' + (remainder / denominator) as u8) as char);
+            remainder %= denominator;
+        }
+
+        prefix.push_str(&digits);
+        (prefix, None)
+    }
+
+    /// Renders the fraction as a percentage with a fixed number of decimals,
+    /// e.g. `3/4` with 2 decimals gives `"75.00%"`.
+    pub fn to_percent_string(&self, decimals: usize) -> String {
+        let numerator = self.numerator as i128 * 100;
+        format!("{}%", decimal_expansion(numerator, self.denominator as i128, decimals))
+    }
+
+    /// Rounds the fraction to an integer using the given mode.
+    pub fn round_with(&self, mode: RoundingMode) -> i64 {
+        let numerator = self.numerator as i128;
+        let denominator = self.denominator as i128;
+        let floor = numerator.div_euclid(denominator);
+        let remainder = numerator.rem_euclid(denominator);
+        if remainder == 0 {
+            return floor as i64;
+        }
+
+        let rounded = match mode {
+            RoundingMode::Floor => floor,
+            RoundingMode::Ceil => floor + 1,
+            RoundingMode::TowardZero => if numerator < 0 { floor + 1 } else { floor },
+            RoundingMode::HalfUp | RoundingMode::HalfEven => match (2 * remainder).cmp(&denominator) {
+                Ordering::Less => floor,
+                Ordering::Greater => floor + 1,
+                Ordering::Equal if mode == RoundingMode::HalfUp => {
+                    if numerator < 0 { floor } else { floor + 1 }
+                }
+                Ordering::Equal => floor + floor.rem_euclid(2),
+            },
+        };
+        rounded as i64
+    }
+
+    /// Returns -1, 0 or 1 according to the sign of the fraction.
+    /// The denominator is always positive after normalization, so this is the numerator's sign.
+    pub fn signum(&self) -> i64 {
+        self.numerator.signum()
+    }
+
+    /// Checks if the fraction is strictly less than zero.
+    pub fn is_negative(&self) -> bool {
+        self.numerator < 0
+    }
+
+    /// Checks if the fraction is strictly greater than zero.
+    pub fn is_positive(&self) -> bool {
+        self.numerator > 0
+    }
+
+    /// Restricts the fraction to the range `[lo, hi]`.
+    /// `lo <= hi` is only checked in debug builds.
+    pub fn clamp(self, lo: Fraction, hi: Fraction) -> Fraction {
+        debug_assert!(lo <= hi, "clamp requires lo <= hi");
+        if self < lo {
+            lo
+        } else if self > hi {
+            hi
+        } else {
+            self
+        }
     }
 
     /// Checks if the fraction is proper.
     pub fn is_proper(&self) -> bool {
         self.numerator.abs() < self.denominator.abs()
     }
+
+    /// Decomposes a fraction strictly between 0 and 1 into distinct unit
+    /// fractions with the greedy Fibonacci-Sylvester algorithm, largest first,
+    /// e.g. `2/3` gives `[1/2, 1/6]`.
+    /// Panics if the fraction is not in `(0, 1)` or a denominator overflows `i64`.
+    pub fn to_egyptian(&self) -> Vec<Fraction> {
+        if self.numerator <= 0 || !self.is_proper() {
+            panic!("Egyptian fractions need a value strictly between 0 and 1!");
+        }
+
+        let mut units = Vec::new();
+        let (mut numerator, mut denominator) = (self.numerator as i128, self.denominator as i128);
+        while numerator != 0 {
+            // The largest unit fraction not exceeding numerator/denominator.
+            let unit = (denominator + numerator - 1) / numerator;
+            let unit_denominator = i64::try_from(unit).expect("Egyptian fraction denominator overflowed!");
+            units.push(Fraction { numerator: 1, denominator: unit_denominator });
+
+            let next_numerator = numerator * unit - denominator;
+            let next_denominator = denominator * unit;
+            let divisor = gcd(next_numerator, next_denominator);
+            (numerator, denominator) = (next_numerator / divisor, next_denominator / divisor);
+        }
+        units
+    }
+
+    /// Returns the `L`/`R` moves leading from the root `1/1` of the
+    /// Stern-Brocot tree to this fraction, e.g. `"LRL"` for `3/5`.
+    /// Panics unless the fraction is positive.
+    pub fn stern_brocot_path(&self) -> Vec<char> {
+        if self.numerator <= 0 {
+            panic!("Stern-Brocot paths exist only for positive fractions!");
+        }
+
+        let (mut m, mut n) = (self.numerator, self.denominator);
+        let mut path = Vec::new();
+        while m != n {
+            if m < n {
+                path.push('L');
+                n -= m;
+            } else {
+                path.push('R');
+                m -= n;
+            }
+        }
+        path
+    }
+
+    /// Follows `L`/`R` moves down the Stern-Brocot tree from `1/1`,
+    /// inverting `stern_brocot_path`. Panics on any other character.
+    pub fn from_stern_brocot_path(path: &[char]) -> Fraction {
+        let (mut left, mut right) = ((0, 1), (1, 0));
+        for &step in path {
+            let mediant = (left.0 + right.0, left.1 + right.1);
+            match step {
+                'L' => right = mediant,
+                'R' => left = mediant,
+                _ => panic!("Stern-Brocot paths contain only 'L' and 'R'!"),
+            }
+        }
+        Fraction { numerator: left.0 + right.0, denominator: left.1 + right.1 }
+    }
+
+    /// Multiplies by `10^k` for positive `k` or divides by `10^|k|` for
+    /// negative `k`, reducing the result.
+    /// Panics if the scaled fraction does not fit in `i64` parts.
+    pub fn scale_pow10(&self, k: i32) -> Fraction {
+        let scaled = 10i128.checked_pow(k.unsigned_abs()).and_then(|factor| {
+            let (numerator, denominator) = (self.numerator as i128, self.denominator as i128);
+            if k >= 0 {
+                numerator.checked_mul(factor).map(|n| (n, denominator))
+            } else {
+                denominator.checked_mul(factor).map(|d| (numerator, d))
+            }
+        });
+        scaled
+            .and_then(|(numerator, denominator)| reduce_wide(numerator, denominator).ok())
+            .expect("Scaled fraction does not fit in 64-bit integers!")
+    }
+
+    /// Interpolates exactly between two fractions: `self + (other - self) * t`.
+    /// `t = 0` gives `self` and `t = 1` gives `other`.
+    pub fn lerp(&self, other: &Fraction, t: &Fraction) -> Fraction {
+        self.clone() + (other.clone() - self.clone()) * t.clone()
+    }
+
+    /// Returns the exact midpoint of two fractions.
+    pub fn midpoint(&self, other: &Fraction) -> Fraction {
+        self.lerp(other, &Fraction::new(1, 2))
+    }
+
+    /// Returns true if the decimal expansion terminates, i.e. the reduced
+    /// denominator has no prime factors other than 2 and 5.
+    pub fn is_terminating_decimal(&self) -> bool {
+        let mut denominator = self.denominator;
+        for p in [2, 5] {
+            while denominator % p == 0 {
+                denominator /= p;
+            }
+        }
+        denominator == 1
+    }
+
+    /// Returns true if the reduced denominator is a power of two.
+    pub fn is_dyadic(&self) -> bool {
+        (self.denominator as u64).is_power_of_two()
+    }
+
+    /// Compares the fraction with a whole number without building a Fraction,
+    /// widening to `i128` so `n * denominator` cannot overflow.
+    pub fn cmp_to_int(&self, n: i64) -> Ordering {
+        (self.numerator as i128).cmp(&(n as i128 * self.denominator as i128))
+    }
+
+    /// Compares the fraction with the exact value of an `f64`, without rounding
+    /// either side. The float is split into `±m * 2^e` and both sides are
+    /// cross-multiplied as LargeInts. Infinities lie beyond every fraction;
+    /// NaN gives `None`.
+    pub fn cmp_f64(&self, x: f64) -> Option<Ordering> {
+        if x.is_nan() {
+            return None;
+        }
+        if x.is_infinite() {
+            return Some(if x > 0.0 { Ordering::Less } else { Ordering::Greater });
+        }
+
+        let bits = x.to_bits();
+        let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+        let fraction_bits = bits & ((1 << 52) - 1);
+        let (mantissa, exponent) = if biased_exponent == 0 {
+            (fraction_bits, -1074)
+        } else {
+            (fraction_bits | 1 << 52, biased_exponent - 1075)
+        };
+        let sign = LargeInt::from(if x.is_sign_negative() { -1 } else { 1 });
+
+        let mut left = LargeInt::from(self.numerator);
+        let mut right = multiply(
+            &multiply(&LargeInt::from_u64(mantissa), &sign),
+            &LargeInt::from(self.denominator),
+        );
+        let scale = LargeInt::pow_of_two(exponent.unsigned_abs());
+        if exponent >= 0 {
+            right = multiply(&right, &scale);
+        } else {
+            left = multiply(&left, &scale);
+        }
+        Some(left.compare_signed(&right))
+    }
 }
 
 impl fmt::Display for Fraction {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.is_proper() {
-            write!(f, "{}/{}", self.numerator, self.denominator)
-        } else {
-            let (whole, remainder) = self.to_mixed();
-            if remainder.numerator == 0 {
-                write!(f, "{}", whole)
+        write!(f, "{}", self.to_mixed_string())
+    }
+}
+
+/// Returns the Farey sequence of order `n`: every reduced fraction in `[0, 1]`
+/// with denominator at most `n`, in ascending order. Each term is derived from
+/// the previous two with the mediant recurrence. Order 0 gives an empty sequence.
+pub fn farey_sequence(n: u64) -> Vec<Fraction> {
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let n = n as i64;
+    let (mut a, mut b, mut c, mut d) = (0, 1, 1, n);
+    let mut sequence = vec![Fraction { numerator: a, denominator: b }];
+    while c <= n {
+        let k = (n + b) / d;
+        (a, b, c, d) = (c, d, k * c - a, k * d - b);
+        sequence.push(Fraction { numerator: a, denominator: b });
+    }
+    sequence
+}
+
+/// Finds the rational roots of the integer polynomial whose coefficients are
+/// given from the highest degree down, so `[1, 0, -1]` is `x² - 1`.
+/// By the rational-root theorem every root is some ±p/q with p dividing the
+/// constant term and q dividing the leading coefficient; each candidate is
+/// checked exactly and the roots are returned once each in ascending order.
+/// A zero polynomial has no well-defined root set and yields an empty vector.
+/// Roots whose numerator or denominator would be ±2^63 cannot be represented
+/// and are left out. Finding the divisors costs up to √|c| steps only when a
+/// coefficient c is the product of two large primes; see `divisors`.
+pub fn rational_roots(coeffs: &[i64]) -> Vec<Fraction> {
+    let start = coeffs.iter().position(|&c| c != 0).unwrap_or(coeffs.len());
+    let mut coeffs = &coeffs[start..];
+    let mut roots = Vec::new();
+    // A zero constant term means x divides the polynomial.
+    while let Some((&0, rest)) = coeffs.split_last() {
+        if roots.is_empty() {
+            roots.push(Fraction::from(0));
+        }
+        coeffs = rest;
+    }
+    let (Some(&leading), Some(&constant)) = (coeffs.first(), coeffs.last()) else {
+        return roots;
+    };
+
+    // Divisors of 2^63 include 2^63 itself, which no Fraction can hold.
+    let representable = |d: u64| i64::try_from(d).ok();
+    for p in divisors(constant.unsigned_abs()).into_iter().filter_map(representable) {
+        for q in divisors(leading.unsigned_abs()).into_iter().filter_map(representable) {
+            for p in [p, -p] {
+                let candidate = Fraction::new(p, q);
+                if evaluates_to_zero(coeffs, &candidate) {
+                    roots.push(candidate);
+                }
+            }
+        }
+    }
+
+    roots.sort();
+    roots.dedup();
+    roots
+}
+
+/// Scales a row of fractions to a common denominator, the LCM of all the
+/// denominators, returning the integer numerators and that denominator.
+/// `[1/2, 1/3, 1/6]` clears to `([3, 2, 1], 6)`. An empty row gives `([], 1)`.
+pub fn clear_denominators(row: &[Fraction]) -> (Vec<LargeInt>, LargeInt) {
+    let common = row.iter().fold(LargeInt::one(), |lcm, fraction| {
+        let denominator = LargeInt::from(fraction.denominator);
+        multiply(&divide(&lcm, &crate::gcd(&lcm, &denominator)), &denominator)
+    });
+    let numerators = row
+        .iter()
+        .map(|fraction| {
+            let scale = divide(&common, &LargeInt::from(fraction.denominator));
+            multiply(&LargeInt::from(fraction.numerator), &scale)
+        })
+        .collect();
+    (numerators, common)
+}
+
+/// Every positive divisor of n > 0, in no particular order, built from its
+/// prime factorisation. Trial division stops as soon as the cofactor is prime
+/// (tested only when it changes), so the full √n scan (about 3 * 10^9 steps near 2^63) only happens when n
+/// is the product of two primes that are both close to √n.
+fn divisors(n: u64) -> Vec<u64> {
+    let is_prime = |m: u64| m > 1 && is_probable_prime(&LargeInt::from_u64(m));
+    let mut factors = Vec::new();
+    let mut rest = n;
+    let mut rest_is_prime = is_prime(rest);
+    let mut p = 2;
+    while !rest_is_prime && p <= rest / p {
+        if rest.is_multiple_of(p) {
+            let mut multiplicity = 0;
+            while rest.is_multiple_of(p) {
+                rest /= p;
+                multiplicity += 1;
+            }
+            factors.push((p, multiplicity));
+            rest_is_prime = is_prime(rest);
+        }
+        p += if p == 2 { 1 } else { 2 };
+    }
+    if rest > 1 {
+        factors.push((rest, 1));
+    }
+
+    let mut found = vec![1];
+    for (prime, multiplicity) in factors {
+        let mut powers = Vec::with_capacity(found.len() * multiplicity as usize);
+        let mut power = 1;
+        for _ in 0..multiplicity {
+            power *= prime;
+            powers.extend(found.iter().map(|d| d * power));
+        }
+        found.extend(powers);
+    }
+    found
+}
+
+/// Checks P(p/q) = 0 exactly by evaluating q^deg * P(p/q) with LargeInts,
+/// so large candidates and high degrees cannot overflow.
+fn evaluates_to_zero(coeffs: &[i64], root: &Fraction) -> bool {
+    let (p, q) = (LargeInt::from(root.numerator), LargeInt::from(root.denominator));
+    let mut q_power = LargeInt::one();
+    let mut value = LargeInt::from(coeffs[0]);
+    for &c in &coeffs[1..] {
+        q_power = multiply(&q_power, &q);
+        value = multiply(&value, &p).add(&multiply(&LargeInt::from(c), &q_power));
+    }
+    value.is_zero()
+}
+
+/// Reduces an `i128` ratio with a positive denominator and narrows it to a Fraction.
+fn reduce_wide(numerator: i128, denominator: i128) -> Result<Fraction, FractionError> {
+    let divisor = gcd(numerator, denominator);
+    let narrow = |n: i128| i64::try_from(n / divisor).map_err(|_| FractionError::Overflow);
+    Ok(Fraction {
+        numerator: narrow(numerator)?,
+        denominator: narrow(denominator)?,
+    })
+}
+
+/// Long-divides `numerator / denominator` to `decimals` places, rounding the
+/// last place half away from zero. The denominator must be positive.
+fn decimal_expansion(numerator: i128, denominator: i128, decimals: usize) -> String {
+    let mut whole = numerator.abs() / denominator;
+    let mut remainder = numerator.abs() % denominator;
+
+    let mut places = Vec::with_capacity(decimals);
+    for _ in 0..decimals {
+        remainder *= 10;
+        places.push((remainder / denominator) as u8);
+        remainder %= denominator;
+    }
+
+    if remainder * 2 >= denominator {
+        let mut carry = true;
+        for place in places.iter_mut().rev() {
+            if *place == 9 {
+                *place = 0;
             } else {
-                write!(f, "{} {}/{}", whole, remainder.numerator.abs(), remainder.denominator)
+                *place += 1;
+                carry = false;
+                break;
             }
         }
+        if carry {
+            whole += 1;
+        }
+    }
+
+    let mut result = String::new();
+    if numerator < 0 && (whole != 0 || places.iter().any(|&d| d != 0)) {
+        result.push('-');
+    }
+    result.push_str(&whole.to_string());
+    if decimals > 0 {
+        result.push('.');
+        result.extend(places.iter().map(|&d| (b'0' + d) as char));
+    }
+    result
+}
+
+/// Displays a fraction in improper `num/den` form instead of the mixed default.
+pub struct ImproperDisplay<'a>(pub &'a Fraction);
+
+impl fmt::Display for ImproperDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.0.numerator, self.0.denominator)
+    }
+}
+
+impl Fraction {
+    /// Parses a fraction written as `a/b`, a plain integer `a`, or a mixed
+    /// number `w a/b` such as `-2 1/3` (the whole part carries the sign).
+    /// A trailing Unicode vulgar fraction is also accepted, as in `½` or `-2¾`.
+    /// The error says which of these rules the input broke.
+    pub fn checked_from_str(input: &str) -> Result<Fraction, ParseFractionError> {
+        let input = input.trim();
+        if input.is_empty() {
+            return Err(ParseFractionError::new(ParseFractionErrorKind::Empty));
+        }
+        if let Some(symbol) = input.chars().last().filter(|c| !c.is_ascii()) {
+            return parse_vulgar_fraction(input, symbol);
+        }
+        if let Some((whole, rest)) = input.split_once(char::is_whitespace) {
+            let rest = rest.trim_start();
+            if !whole.contains('/') && !rest.starts_with('/') {
+                let invalid = ParseFractionError::new(ParseFractionErrorKind::InvalidNumber);
+                let whole = parse_number(whole)?;
+                // Only the `w a/b` form `to_mixed_string` writes, with 0 < a < b.
+                if !rest.contains('/') {
+                    return Err(invalid);
+                }
+                let part = parse_simple_fraction(rest)?;
+                if !part.is_positive() || !part.is_proper() {
+                    return Err(invalid);
+                }
+                let magnitude = Fraction::new(whole.abs(), 1).checked_add(&part).map_err(|_| invalid)?;
+                return Ok(if input.starts_with('-') {
+                    Fraction::new(-magnitude.numerator, magnitude.denominator)
+                } else {
+                    magnitude
+                });
+            }
+        }
+
+        parse_simple_fraction(input)
+    }
+}
+
+impl FromStr for Fraction {
+    type Err = String;
+
+    /// Parses like `Fraction::checked_from_str`, describing the error as text.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Fraction::checked_from_str(input).map_err(|err| err.to_string())
+    }
+}
+
+/// Maps a Unicode vulgar fraction character such as `½` to its value.
+fn vulgar_fraction(symbol: char) -> Option<(i64, i64)> {
+    let value = match symbol {
+        '½' => (1, 2),
+        '⅓' => (1, 3),
+        '⅔' => (2, 3),
+        '¼' => (1, 4),
+        '¾' => (3, 4),
+        '⅕' => (1, 5),
+        '⅖' => (2, 5),
+        '⅗' => (3, 5),
+        '⅘' => (4, 5),
+        '⅙' => (1, 6),
+        '⅚' => (5, 6),
+        '⅐' => (1, 7),
+        '⅛' => (1, 8),
+        '⅜' => (3, 8),
+        '⅝' => (5, 8),
+        '⅞' => (7, 8),
+        '⅑' => (1, 9),
+        '⅒' => (1, 10),
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// Parses an optional signed whole part followed by a vulgar fraction
+/// character, e.g. `2¾` or `-½`.
+fn parse_vulgar_fraction(input: &str, symbol: char) -> Result<Fraction, ParseFractionError> {
+    let (numerator, denominator) = vulgar_fraction(symbol)
+        .ok_or(ParseFractionError::new(ParseFractionErrorKind::UnknownSymbol(symbol)))?;
+    let whole = input[..input.len() - symbol.len_utf8()].trim();
+    let (negative, digits) = match whole.strip_prefix('-') {
+        Some(rest) => (true, rest.trim()),
+        None => (false, whole.strip_prefix('+').unwrap_or(whole).trim()),
+    };
+    let whole = if digits.is_empty() {
+        0
+    } else if digits.chars().all(|c| c.is_ascii_digit()) {
+        parse_number(digits)?
+    } else {
+        return Err(ParseFractionError::new(ParseFractionErrorKind::InvalidNumber));
+    };
+
+    let magnitude = Fraction::new(whole, 1)
+        .checked_add(&Fraction::new(numerator, denominator))
+        .map_err(|_| ParseFractionError::new(ParseFractionErrorKind::InvalidNumber))?;
+    Ok(if negative { Fraction::new(-magnitude.numerator, magnitude.denominator) } else { magnitude })
+}
+
+/// Parses `a/b` or a plain integer `a`.
+fn parse_simple_fraction(input: &str) -> Result<Fraction, ParseFractionError> {
+    let (numerator, denominator) = match input.trim().split_once('/') {
+        Some((_, den)) if den.contains('/') => {
+            return Err(ParseFractionError::new(ParseFractionErrorKind::TooManySlashes))
+        }
+        Some((num, den)) => (num.trim(), den.trim()),
+        None => (input.trim(), "1"),
+    };
+
+    let numerator = parse_number(numerator)?;
+    let denominator = parse_number(denominator)?;
+    if denominator == 0 {
+        return Err(ParseFractionError::new(ParseFractionErrorKind::ZeroDenominator));
+    }
+
+    Ok(Fraction::new(numerator, denominator))
+}
+
+/// Parses one integer component of a fraction. `i64::MIN` is rejected along
+/// with non-integers, since a Fraction cannot take its absolute value.
+fn parse_number(text: &str) -> Result<i64, ParseFractionError> {
+    text.parse::<i64>()
+        .ok()
+        .filter(|&n| n != i64::MIN)
+        .ok_or(ParseFractionError::new(ParseFractionErrorKind::InvalidNumber))
+}
+
+impl PartialOrd for Fraction {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Fraction {
+    /// Compares by cross-multiplying in `i128`, relying on positive denominators.
+    fn cmp(&self, other: &Self) -> Ordering {
+        let left = self.numerator as i128 * other.denominator as i128;
+        let right = other.numerator as i128 * self.denominator as i128;
+        left.cmp(&right)
+    }
+}
+
+impl From<i64> for Fraction {
+    fn from(n: i64) -> Self {
+        Fraction::new(n, 1)
+    }
+}
+
+impl From<(i64, i64)> for Fraction {
+    /// Panics on a zero denominator, like `Fraction::new`.
+    fn from((numerator, denominator): (i64, i64)) -> Self {
+        Fraction::new(numerator, denominator)
     }
 }
 
@@ -108,17 +1074,20 @@ impl Mul for Fraction {
     }
 }
 
+/// Panics when dividing by zero or on overflow; use `try_div` or
+/// `checked_div` for untrusted input.
 impl Div for Fraction {
     type Output = Fraction;
 
     fn div(self, other: Fraction) -> Fraction {
-        self * other.reciprocal()
+        self.try_div(&other).expect("Cannot divide by a zero fraction!")
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::time::{Duration, Instant};
 
     #[test]
     fn test_fraction_creation() {
@@ -163,4 +1132,603 @@ mod tests {
         let frac = Fraction::new(7, 3);
         assert_eq!(format!("{}", frac), "2 1/3");
     }
+
+    #[test]
+    fn test_display_improper() {
+        let frac = Fraction::new(7, 3);
+        assert_eq!(format!("{}", frac), "2 1/3");
+        assert_eq!(format!("{}", frac.display_improper()), "7/3");
+        assert_eq!(format!("{}", ImproperDisplay(&Fraction::new(-4, 2))), "-2/1");
+    }
+
+    #[test]
+    fn test_from_ratio() {
+        let n = LargeInt::parse("300000000000000000000000000021");
+        let d = LargeInt::parse("-400000000000000000000000000028");
+        assert_eq!(Fraction::from_ratio(n, d), Ok(Fraction::new(-3, 4)));
+
+        let n = LargeInt::parse("123456789012345678901234567890");
+        let d = LargeInt::parse("987654321098765432109876543210");
+        assert_eq!(Fraction::from_ratio(n, d), Ok(Fraction::new(13717421, 109739369)));
+
+        assert_eq!(
+            Fraction::from_ratio(LargeInt::one(), LargeInt::zero()),
+            Err(FractionError::ZeroDenominator)
+        );
+        assert_eq!(
+            Fraction::from_ratio(LargeInt::parse("123456789012345678901234567891"), LargeInt::one()),
+            Err(FractionError::Overflow)
+        );
+        assert_eq!(
+            Fraction::from_ratio(LargeInt::from(i64::MAX), LargeInt::parse("-1")),
+            Ok(Fraction::new(-i64::MAX, 1))
+        );
+        assert_eq!(
+            Fraction::from_ratio(LargeInt::from(i64::MIN), LargeInt::one()),
+            Err(FractionError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_to_percent_string() {
+        assert_eq!(Fraction::new(3, 4).to_percent_string(2), "75.00%");
+        assert_eq!(Fraction::new(1, 3).to_percent_string(4), "33.3333%");
+        assert_eq!(Fraction::new(2, 3).to_percent_string(1), "66.7%");
+        assert_eq!(Fraction::new(1, 1).to_percent_string(2), "100.00%");
+        assert_eq!(Fraction::new(-1, 8).to_percent_string(0), "-13%");
+        assert_eq!(Fraction::new(-1, 100000).to_percent_string(2), "0.00%");
+    }
+
+    #[test]
+    fn test_to_decimal_string() {
+        assert_eq!(Fraction::new(1, 8).to_decimal_string(3), "0.125");
+        assert_eq!(Fraction::new(1, 8).to_decimal_string(5), "0.12500");
+        assert_eq!(Fraction::new(2, 3).to_decimal_string(4), "0.6667");
+        assert_eq!(Fraction::new(1, 3).to_decimal_string(4), "0.3333");
+        assert_eq!(Fraction::new(-7, 2).to_decimal_string(1), "-3.5");
+        assert_eq!(Fraction::new(-7, 2).to_decimal_string(0), "-4");
+        assert_eq!(Fraction::new(999, 1000).to_decimal_string(2), "1.00");
+    }
+
+    #[test]
+    fn test_to_repeating_decimal() {
+        let repeating = |n, d| Fraction::new(n, d).to_repeating_decimal();
+        assert_eq!(repeating(1, 3), ("0.".to_string(), Some("3".to_string())));
+        assert_eq!(repeating(1, 6), ("0.1".to_string(), Some("6".to_string())));
+        assert_eq!(repeating(1, 7), ("0.".to_string(), Some("142857".to_string())));
+        assert_eq!(repeating(-22, 7), ("-3.".to_string(), Some("142857".to_string())));
+        assert_eq!(repeating(1, 8), ("0.125".to_string(), None));
+        assert_eq!(repeating(4, 2), ("2".to_string(), None));
+    }
+
+    #[test]
+    fn test_checked_add_and_mul() {
+        let a = Fraction::new(1, 2);
+        let b = Fraction::new(1, 3);
+        assert_eq!(a.checked_add(&b), Ok(Fraction::new(5, 6)));
+        assert_eq!(a.checked_mul(&b), Ok(Fraction::new(1, 6)));
+
+        // The intermediate overflows i64 but the reduced result fits.
+        let big = Fraction::new(i64::MAX, 3);
+        assert_eq!(big.checked_mul(&Fraction::new(3, i64::MAX)), Ok(Fraction::new(1, 1)));
+
+        let huge = Fraction::new(i64::MAX, 1);
+        assert_eq!(huge.checked_mul(&Fraction::new(2, 1)), Err(FractionError::Overflow));
+        assert_eq!(huge.checked_add(&Fraction::new(1, 1)), Err(FractionError::Overflow));
+    }
+
+    #[test]
+    fn test_from_conversions() {
+        let whole: Fraction = 5.into();
+        assert_eq!(whole, Fraction::new(5, 1));
+        assert_eq!(Fraction::from((6, -8)), Fraction::new(-3, 4));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_tuple_zero_denominator() {
+        let _ = Fraction::from((1, 0));
+    }
+
+    #[test]
+    fn test_reduce_and_sum_all() {
+        let terms = [Fraction::new(1, 2), Fraction::new(1, 3), Fraction::new(1, 6)];
+        assert_eq!(Fraction::sum_all(&terms), Fraction::new(1, 1));
+        assert_eq!(Fraction::sum_all(&[]), Fraction::new(0, 1));
+
+        let mut raw = [
+            Fraction { numerator: 2, denominator: 4 },
+            Fraction { numerator: 3, denominator: 9 },
+        ];
+        Fraction::reduce_all(&mut raw);
+        assert_eq!(raw, [Fraction::new(1, 2), Fraction::new(1, 3)]);
+        assert_eq!(raw[0].denominator, 2);
+    }
+
+    #[test]
+    fn test_weighted_mean() {
+        let ones = [Fraction::new(1, 1), Fraction::new(1, 1)];
+        let values = [Fraction::new(1, 1), Fraction::new(3, 1)];
+        assert_eq!(Fraction::weighted_mean(&values, &ones), Ok(Fraction::new(2, 1)));
+
+        let values = [Fraction::new(1, 2), Fraction::new(1, 3)];
+        let weights = [Fraction::new(1, 4), Fraction::new(3, 4)];
+        assert_eq!(Fraction::weighted_mean(&values, &weights), Ok(Fraction::new(3, 8)));
+
+        assert_eq!(
+            Fraction::weighted_mean(&values, &ones[..1]),
+            Err(FractionError::LengthMismatch)
+        );
+        let cancelling = [Fraction::new(1, 2), Fraction::new(-1, 2)];
+        assert_eq!(
+            Fraction::weighted_mean(&values, &cancelling),
+            Err(FractionError::ZeroDenominator)
+        );
+        assert_eq!(Fraction::weighted_mean(&[], &[]), Err(FractionError::ZeroDenominator));
+    }
+
+    #[test]
+    #[allow(clippy::approx_constant)]
+    fn test_approx_from_f64() {
+        assert_eq!(Fraction::approx_from_f64(3.14159, 1000), Fraction::new(355, 113));
+        assert_eq!(Fraction::approx_from_f64(0.5, 1000), Fraction::new(1, 2));
+        assert_eq!(Fraction::approx_from_f64(-0.75, 10), Fraction::new(-3, 4));
+        assert_eq!(Fraction::approx_from_f64(std::f64::consts::PI, 10), Fraction::new(22, 7));
+        assert_eq!(Fraction::approx_from_f64(0.1, 1_000_000), Fraction::new(1, 10));
+        assert_eq!(Fraction::approx_from_f64(2.0, 1), Fraction::new(2, 1));
+        assert_eq!(Fraction::approx_from_f64(0.0, 5), Fraction::new(0, 1));
+
+        // Large values with a huge denominator bound stop before leaving i64.
+        assert_eq!(Fraction::approx_from_f64(1e18, i64::MAX), Fraction::new(1_000_000_000_000_000_000, 1));
+        assert_eq!(Fraction::approx_from_f64(-9.2e18, i64::MAX), Fraction::new(-9_200_000_000_000_000_000, 1));
+        assert_eq!(Fraction::approx_from_f64(0.5, i64::MAX), Fraction::new(1, 2));
+        for x in [std::f64::consts::PI, 0.1, 1e15 + 0.3, 123456.789, 1e-18, 4e18 + 512.0] {
+            let approx = Fraction::approx_from_f64(x, i64::MAX);
+            let error = (approx.numerator as f64 / approx.denominator as f64 - x).abs();
+            assert!(error <= x.abs() * 1e-15, "{} ~ {}", x, approx);
+        }
+    }
+
+    #[test]
+    fn test_signum() {
+        let negative = Fraction::new(3, -4);
+        assert_eq!(negative.signum(), -1);
+        assert!(negative.is_negative() && !negative.is_positive());
+
+        let zero = Fraction::new(0, 5);
+        assert_eq!(zero.signum(), 0);
+        assert!(!zero.is_negative() && !zero.is_positive());
+
+        let positive = Fraction::new(-3, -4);
+        assert_eq!(positive.signum(), 1);
+        assert!(positive.is_positive() && !positive.is_negative());
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(Fraction::new(1, 3) < Fraction::new(1, 2));
+        assert!(Fraction::new(-1, 2) < Fraction::new(-1, 3));
+        assert!(Fraction::new(i64::MAX, 2) > Fraction::new(i64::MAX - 1, 2));
+        assert_eq!(Fraction::new(2, 4).cmp(&Fraction::new(1, 2)), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_clamp_min_max() {
+        let (lo, hi) = (Fraction::new(0, 1), Fraction::new(1, 1));
+        assert_eq!(Fraction::new(3, 2).clamp(lo.clone(), hi.clone()), Fraction::new(1, 1));
+        assert_eq!(Fraction::new(-1, 2).clamp(lo.clone(), hi.clone()), Fraction::new(0, 1));
+        assert_eq!(Fraction::new(1, 3).clamp(lo, hi), Fraction::new(1, 3));
+
+        assert_eq!(Fraction::new(1, 3).min(Fraction::new(1, 4)), Fraction::new(1, 4));
+        assert_eq!(Fraction::new(1, 3).max(Fraction::new(1, 4)), Fraction::new(1, 3));
+    }
+
+    #[test]
+    fn test_mixed_string_round_trip() {
+        assert_eq!(Fraction::new(7, 3).to_mixed_string(), "2 1/3");
+        assert_eq!(Fraction::new(-7, 3).to_mixed_string(), "-2 1/3");
+        assert_eq!(Fraction::from_str("-2 1/3"), Ok(Fraction::new(-7, 3)));
+        assert_eq!(Fraction::from_str("  2   1/3 "), Ok(Fraction::new(7, 3)));
+        assert_eq!(Fraction::from_str("1 / 3"), Ok(Fraction::new(1, 3)));
+        assert!(Fraction::from_str("2 -1/3").is_err());
+        assert!(Fraction::from_str("1 2").is_err());
+        assert!(Fraction::from_str("2 5/3").is_err());
+        assert!(Fraction::from_str("2 3/3").is_err());
+        assert!(Fraction::from_str("2 0/3").is_err());
+        assert!(Fraction::from_str("-9223372036854775807 1/2").is_err());
+        assert_eq!(Fraction::from_str("-4611686018427387903 1/2"), Ok(Fraction::new(-i64::MAX, 2)));
+        assert!(Fraction::from_str("2 x/3").is_err());
+
+        for numerator in -60..=60 {
+            for denominator in 1..=12 {
+                let fraction = Fraction::new(numerator, denominator);
+                let text = fraction.to_mixed_string();
+                assert_eq!(Fraction::from_str(&text), Ok(fraction.clone()), "round trip of {}", text);
+                assert_eq!(Fraction::from_str(&fraction.to_string()), Ok(fraction));
+            }
+        }
+    }
+
+    #[test]
+    fn test_try_div() {
+        let half = Fraction::new(1, 2);
+        assert_eq!(half.try_div(&Fraction::new(-3, 4)), Ok(Fraction::new(-2, 3)));
+        assert_eq!(half.try_div(&Fraction::new(0, 5)), Err(FractionError::ZeroDenominator));
+        let big = Fraction::new(i64::MAX, 1);
+        assert_eq!(big.try_div(&Fraction::new(1, 2)), Err(FractionError::Overflow));
+        assert_eq!(half.clone() / Fraction::new(1, 4), Fraction::new(2, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot divide by a zero fraction!")]
+    fn test_div_operator_panics_on_zero() {
+        let _ = Fraction::new(1, 2) / Fraction::new(0, 1);
+    }
+
+    #[test]
+    fn test_checked_div() {
+        let three_quarters = Fraction::new(3, 4);
+        assert_eq!(three_quarters.checked_div(Fraction::new(0, 1)), None);
+        assert_eq!(three_quarters.checked_div(Fraction::new(3, 8)), Some(Fraction::new(2, 1)));
+        assert_eq!(
+            Fraction::new(-1, 3).checked_div(Fraction::new(-2, 5)),
+            Some(Fraction::new(5, 6))
+        );
+    }
+
+    #[test]
+    fn test_round_with() {
+        let half = Fraction::new(1, 2);
+        let three_halves = Fraction::new(3, 2);
+        assert_eq!(half.round_with(RoundingMode::HalfEven), 0);
+        assert_eq!(half.round_with(RoundingMode::HalfUp), 1);
+        assert_eq!(three_halves.round_with(RoundingMode::HalfEven), 2);
+        assert_eq!(three_halves.round_with(RoundingMode::HalfUp), 2);
+
+        let minus_half = Fraction::new(-5, 2);
+        assert_eq!(minus_half.round_with(RoundingMode::HalfEven), -2);
+        assert_eq!(minus_half.round_with(RoundingMode::HalfUp), -3);
+        assert_eq!(minus_half.round_with(RoundingMode::Floor), -3);
+        assert_eq!(minus_half.round_with(RoundingMode::Ceil), -2);
+        assert_eq!(minus_half.round_with(RoundingMode::TowardZero), -2);
+
+        let seven_thirds = Fraction::new(7, 3);
+        assert_eq!(seven_thirds.round_with(RoundingMode::HalfUp), 2);
+        assert_eq!(seven_thirds.round_with(RoundingMode::Ceil), 3);
+        assert_eq!(Fraction::new(-4, 1).round_with(RoundingMode::Ceil), -4);
+    }
+
+    #[test]
+    fn test_cmp_to_int() {
+        assert_eq!(Fraction::new(7, 3).cmp_to_int(2), Ordering::Greater);
+        assert_eq!(Fraction::new(4, 2).cmp_to_int(2), Ordering::Equal);
+        assert_eq!(Fraction::new(-1, 3).cmp_to_int(0), Ordering::Less);
+        assert_eq!(Fraction::new(1, 3).cmp_to_int(i64::MAX), Ordering::Less);
+        assert_eq!(Fraction::new(-1, 3).cmp_to_int(i64::MIN), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_cmp_f64() {
+        let third = Fraction::new(1, 3);
+        assert_eq!(third.cmp_f64(0.3333333333333333), Some(Ordering::Greater));
+        assert_eq!(third.cmp_f64(0.33333333333333337), Some(Ordering::Less));
+        assert_eq!(Fraction::new(1, 10).cmp_f64(0.1), Some(Ordering::Less));
+        assert_eq!(Fraction::new(-3, 4).cmp_f64(-0.75), Some(Ordering::Equal));
+        assert_eq!(Fraction::new(0, 1).cmp_f64(-0.0), Some(Ordering::Equal));
+        assert_eq!(Fraction::new(0, 1).cmp_f64(5e-324), Some(Ordering::Less));
+        assert_eq!(Fraction::new(1, i64::MAX).cmp_f64(f64::MIN_POSITIVE), Some(Ordering::Greater));
+        assert_eq!(Fraction::new(i64::MAX, 1).cmp_f64(i64::MAX as f64), Some(Ordering::Less));
+        assert_eq!(Fraction::new(i64::MAX, 1).cmp_f64(1e300), Some(Ordering::Less));
+        assert_eq!(Fraction::new(-i64::MAX, 1).cmp_f64(-1e300), Some(Ordering::Greater));
+        assert_eq!(third.cmp_f64(f64::INFINITY), Some(Ordering::Less));
+        assert_eq!(third.cmp_f64(f64::NEG_INFINITY), Some(Ordering::Greater));
+        assert_eq!(third.cmp_f64(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_from_parts_verbose() {
+        assert_eq!(Fraction::from_parts_verbose(6, 8), (Fraction::new(3, 4), Some(2)));
+        assert_eq!(Fraction::from_parts_verbose(3, 4), (Fraction::new(3, 4), None));
+        assert_eq!(Fraction::from_parts_verbose(-10, -15), (Fraction::new(2, 3), Some(5)));
+        assert_eq!(Fraction::from_parts_verbose(3, -4), (Fraction::new(-3, 4), None));
+    }
+
+    #[test]
+    fn test_farey_sequence() {
+        let expected: Vec<Fraction> = [
+            (0, 1), (1, 5), (1, 4), (1, 3), (2, 5), (1, 2),
+            (3, 5), (2, 3), (3, 4), (4, 5), (1, 1),
+        ]
+        .iter()
+        .map(|&(n, d)| Fraction::new(n, d))
+        .collect();
+        assert_eq!(farey_sequence(5), expected);
+        assert_eq!(farey_sequence(1), vec![Fraction::new(0, 1), Fraction::new(1, 1)]);
+        assert!(farey_sequence(0).is_empty());
+
+        let order_12 = farey_sequence(12);
+        assert_eq!(order_12.len(), 47);
+        assert!(order_12.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_clear_denominators() {
+        let ints = |values: &[i64]| values.iter().map(|&v| LargeInt::from(v)).collect::<Vec<_>>();
+        let row = [Fraction::new(1, 2), Fraction::new(1, 3), Fraction::new(1, 6)];
+        assert_eq!(clear_denominators(&row), (ints(&[3, 2, 1]), LargeInt::from(6)));
+
+        let row = [Fraction::new(-3, 4), Fraction::new(0, 1), Fraction::new(5, 1)];
+        assert_eq!(clear_denominators(&row), (ints(&[-3, 0, 20]), LargeInt::from(4)));
+        assert_eq!(clear_denominators(&[]), (Vec::new(), LargeInt::one()));
+
+        // The LCM may exceed i64 even though every denominator fits.
+        let big = i64::MAX;
+        let row = [Fraction::new(1, big), Fraction::new(1, big - 1)];
+        let (numerators, common) = clear_denominators(&row);
+        assert_eq!(common, multiply(&LargeInt::from(big), &LargeInt::from(big - 1)));
+        assert_eq!(numerators, ints(&[big - 1, big]));
+    }
+
+    #[test]
+    fn test_rational_roots() {
+        assert_eq!(rational_roots(&[1, 0, -1]), vec![Fraction::new(-1, 1), Fraction::new(1, 1)]);
+        assert_eq!(rational_roots(&[2, -3]), vec![Fraction::new(3, 2)]);
+        // 6x³ - 7x² + 1 = (x - 1)(2x - 1)(3x + 1)
+        assert_eq!(
+            rational_roots(&[6, -7, 0, 1]),
+            vec![Fraction::new(-1, 3), Fraction::new(1, 2), Fraction::new(1, 1)]
+        );
+        // x⁴ - x² = x²(x - 1)(x + 1), with the repeated root at zero listed once.
+        assert_eq!(
+            rational_roots(&[0, 1, 0, -1, 0, 0]),
+            vec![Fraction::new(-1, 1), Fraction::new(0, 1), Fraction::new(1, 1)]
+        );
+        assert!(rational_roots(&[1, 0, 1]).is_empty());
+        assert!(rational_roots(&[1, 0, -2]).is_empty());
+        assert!(rational_roots(&[5]).is_empty());
+        assert!(rational_roots(&[0, 0]).is_empty());
+        assert!(rational_roots(&[]).is_empty());
+
+        // -2^63 x + 1 has the root 1/2^63, which no Fraction can hold.
+        assert!(rational_roots(&[i64::MIN, 1]).is_empty());
+        assert!(rational_roots(&[1, i64::MIN]).is_empty());
+        assert_eq!(rational_roots(&[i64::MIN, i64::MIN]), vec![Fraction::new(-1, 1)]);
+        assert_eq!(rational_roots(&[2, i64::MIN]), vec![Fraction::new(1 << 62, 1)]);
+        // Large prime coefficients factor quickly.
+        let prime = 9223372036854775783;
+        assert_eq!(rational_roots(&[prime, -1]), vec![Fraction::new(1, prime)]);
+        assert_eq!(rational_roots(&[1, -prime]), vec![Fraction::new(prime, 1)]);
+    }
+
+    #[test]
+    fn test_divisors() {
+        let mut found = divisors(360);
+        found.sort();
+        assert_eq!(found.len(), 24);
+        assert_eq!(&found[..6], &[1, 2, 3, 4, 5, 6]);
+        assert_eq!(divisors(1), vec![1]);
+        assert_eq!(divisors(1 << 63).len(), 64);
+        let mut found = divisors(9223372036854775783);
+        found.sort();
+        assert_eq!(found, vec![1, 9223372036854775783]);
+
+        // A semiprime with two factors near 10^6 needs the whole trial-division scan.
+        let started = Instant::now();
+        let mut found = divisors(999962000357);
+        found.sort();
+        assert_eq!(found, vec![1, 999979, 999983, 999962000357]);
+        assert!(rational_roots(&[1, 0, -999962000357]).is_empty());
+        assert!(started.elapsed() < Duration::from_secs(2));
+    }
+
+    #[test]
+    fn test_mul_div_int() {
+        let two_thirds = Fraction::new(2, 3);
+        assert_eq!(two_thirds.mul_int(3), Fraction::new(2, 1));
+        assert_eq!(two_thirds.mul_int(-6), Fraction::new(-4, 1));
+        assert_eq!(two_thirds.mul_int(0), Fraction::new(0, 1));
+        assert_eq!(Fraction::new(i64::MAX, 3).mul_int(3), Fraction::new(i64::MAX, 1));
+        assert_eq!(Fraction::new(1, 2).mul_int(i64::MIN), Fraction::new(i64::MIN / 2, 1));
+        assert_eq!(two_thirds.div_int(2), Ok(Fraction::new(1, 3)));
+        assert_eq!(two_thirds.div_int(-4), Ok(Fraction::new(-1, 6)));
+        assert_eq!(two_thirds.div_int(0), Err(FractionError::ZeroDenominator));
+        assert_eq!(Fraction::new(1, i64::MAX).div_int(2), Err(FractionError::Overflow));
+    }
+
+    #[test]
+    #[should_panic(expected = "Product does not fit in 64-bit integers!")]
+    fn test_mul_int_overflow() {
+        Fraction::new(i64::MAX, 2).mul_int(3);
+    }
+
+    #[test]
+    fn test_convergents() {
+        let fraction = Fraction::new(415, 93);
+        assert_eq!(fraction.continued_fraction(), vec![4, 2, 6, 7]);
+        let convergents = fraction.convergents();
+        assert_eq!(
+            convergents,
+            vec![Fraction::new(4, 1), Fraction::new(9, 2), Fraction::new(58, 13), fraction]
+        );
+
+        let negative = Fraction::new(-7, 3);
+        assert_eq!(negative.continued_fraction(), vec![-3, 1, 2]);
+        assert_eq!(negative.convergents().last(), Some(&negative));
+        assert_eq!(Fraction::new(5, 1).convergents(), vec![Fraction::new(5, 1)]);
+    }
+
+    #[test]
+    fn test_from_decimal_str() {
+        assert_eq!(Fraction::from_decimal_str("-1.25"), Ok(Fraction::new(-5, 4)));
+        assert_eq!(Fraction::from_decimal_str("0.5"), Ok(Fraction::new(1, 2)));
+        assert_eq!(Fraction::from_decimal_str("42"), Ok(Fraction::new(42, 1)));
+        assert_eq!(Fraction::from_decimal_str(".75"), Ok(Fraction::new(3, 4)));
+        assert_eq!(Fraction::from_decimal_str("3."), Ok(Fraction::new(3, 1)));
+        let kind = |input: &str| Fraction::from_decimal_str(input).unwrap_err().kind;
+        assert_eq!(kind("  "), ParseFractionErrorKind::Empty);
+        assert_eq!(kind("."), ParseFractionErrorKind::InvalidNumber);
+        assert_eq!(kind("1.2.3"), ParseFractionErrorKind::InvalidNumber);
+        assert_eq!(kind("1e5"), ParseFractionErrorKind::InvalidNumber);
+        assert_eq!(kind("0.00000000000000000001"), ParseFractionErrorKind::InvalidNumber);
+    }
+
+    #[test]
+    fn test_lerp_and_midpoint() {
+        let zero = Fraction::new(0, 1);
+        let one = Fraction::new(1, 1);
+        assert_eq!(zero.midpoint(&one), Fraction::new(1, 2));
+        assert_eq!(zero.lerp(&one, &Fraction::new(1, 3)), Fraction::new(1, 3));
+        assert_eq!(Fraction::new(1, 4).lerp(&Fraction::new(3, 4), &zero), Fraction::new(1, 4));
+        assert_eq!(Fraction::new(1, 4).lerp(&Fraction::new(3, 4), &one), Fraction::new(3, 4));
+        assert_eq!(Fraction::new(-1, 3).midpoint(&Fraction::new(1, 2)), Fraction::new(1, 12));
+    }
+
+    #[test]
+    fn test_to_egyptian() {
+        let two_thirds = Fraction::new(2, 3);
+        let units = two_thirds.to_egyptian();
+        assert_eq!(units, vec![Fraction::new(1, 2), Fraction::new(1, 6)]);
+        assert_eq!(Fraction::sum_all(&units), two_thirds);
+
+        let units = Fraction::new(4, 13).to_egyptian();
+        assert_eq!(units, vec![Fraction::new(1, 4), Fraction::new(1, 18), Fraction::new(1, 468)]);
+        assert_eq!(Fraction::sum_all(&units), Fraction::new(4, 13));
+        assert_eq!(Fraction::new(1, 7).to_egyptian(), vec![Fraction::new(1, 7)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Egyptian fractions need a value strictly between 0 and 1!")]
+    fn test_to_egyptian_rejects_improper() {
+        Fraction::new(3, 2).to_egyptian();
+    }
+
+    #[test]
+    fn test_exponentiate_big() {
+        let pow = |f: &Fraction, e: &str| f.exponentiate_big(&LargeInt::parse(e));
+        let ten = Fraction::new(10, 1);
+        assert_eq!(pow(&ten, "18"), Ok(Fraction::new(1_000_000_000_000_000_000, 1)));
+        assert_eq!(pow(&ten, "50"), Err(FractionError::Overflow));
+        assert_eq!(pow(&Fraction::new(-2, 3), "-3"), Ok(Fraction::new(-27, 8)));
+        assert_eq!(pow(&Fraction::new(-1, 1), "123456789123456789123"), Ok(Fraction::new(-1, 1)));
+        assert_eq!(pow(&Fraction::new(5, 7), "0"), Ok(Fraction::new(1, 1)));
+        assert_eq!(pow(&Fraction::new(0, 1), "-2"), Err(FractionError::ZeroDenominator));
+    }
+
+    #[test]
+    fn test_stern_brocot_path() {
+        let three_fifths = Fraction::new(3, 5);
+        assert_eq!(three_fifths.stern_brocot_path(), vec!['L', 'R', 'L']);
+        assert_eq!(Fraction::from_stern_brocot_path(&['L', 'R', 'L']), three_fifths);
+        assert!(Fraction::new(1, 1).stern_brocot_path().is_empty());
+        assert_eq!(Fraction::new(3, 1).stern_brocot_path(), vec!['R', 'R']);
+
+        for numerator in 1..=20 {
+            for denominator in 1..=20 {
+                let fraction = Fraction::new(numerator, denominator);
+                let path = fraction.stern_brocot_path();
+                assert_eq!(Fraction::from_stern_brocot_path(&path), fraction);
+            }
+        }
+    }
+
+    #[test]
+    fn test_terminating_and_dyadic() {
+        let eighth = Fraction::new(1, 8);
+        assert!(eighth.is_terminating_decimal() && eighth.is_dyadic());
+        let sixth = Fraction::new(1, 6);
+        assert!(!sixth.is_terminating_decimal() && !sixth.is_dyadic());
+        let tenth = Fraction::new(1, 10);
+        assert!(tenth.is_terminating_decimal() && !tenth.is_dyadic());
+        let whole = Fraction::new(-6, 3);
+        assert!(whole.is_terminating_decimal() && whole.is_dyadic());
+        assert!(!Fraction::new(7, 30).is_terminating_decimal());
+    }
+
+    #[test]
+    fn test_negative_mixed_display() {
+        let cases = [
+            ((-7, 3), "-2 1/3"),
+            ((-1, 1), "-1"),
+            ((-2, 3), "-2/3"),
+            ((-1, 3), "-1/3"),
+            ((-5, 3), "-1 2/3"),
+            ((-6, 3), "-2"),
+            ((7, -3), "-2 1/3"),
+            ((-22, 7), "-3 1/7"),
+            ((0, 5), "0"),
+        ];
+        for ((numerator, denominator), expected) in cases {
+            assert_eq!(Fraction::new(numerator, denominator).to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_scale_pow10() {
+        assert_eq!(Fraction::new(1, 2).scale_pow10(2), Fraction::new(50, 1));
+        assert_eq!(Fraction::new(50, 1).scale_pow10(-2), Fraction::new(1, 2));
+        assert_eq!(Fraction::new(-3, 7).scale_pow10(0), Fraction::new(-3, 7));
+        assert_eq!(Fraction::from_decimal_str("1.25").unwrap().scale_pow10(2), Fraction::new(125, 1));
+        assert_eq!(Fraction::new(1, 1).scale_pow10(18), Fraction::new(1_000_000_000_000_000_000, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Scaled fraction does not fit in 64-bit integers!")]
+    fn test_scale_pow10_overflow() {
+        Fraction::new(1, 1).scale_pow10(19);
+    }
+
+    #[test]
+    fn test_to_mixed_big() {
+        let (whole, remainder) = Fraction::new(i64::MAX, 2).to_mixed_big();
+        assert_eq!(whole.to_string(), "4611686018427387903");
+        assert_eq!(remainder, Fraction::new(1, 2));
+
+        let (whole, remainder) = Fraction::new(i64::MIN + 1, 10).to_mixed_big();
+        assert_eq!(whole.to_string(), "-922337203685477580");
+        assert_eq!(remainder, Fraction::new(-7, 10));
+
+        for numerator in -40..=40 {
+            let fraction = Fraction::new(numerator, 7);
+            let (whole, remainder) = fraction.to_mixed();
+            assert_eq!(fraction.to_mixed_big(), (LargeInt::from(whole), remainder));
+        }
+    }
+
+    #[test]
+    fn test_parse_vulgar_fractions() {
+        assert_eq!(Fraction::from_str("½"), Ok(Fraction::new(1, 2)));
+        assert_eq!(Fraction::from_str("2¾"), Ok(Fraction::new(11, 4)));
+        assert_eq!(Fraction::from_str(" -1 ⅓ "), Ok(Fraction::new(-4, 3)));
+        assert_eq!(Fraction::from_str("-⅞"), Ok(Fraction::new(-7, 8)));
+        assert_eq!(Fraction::from_str("10⅒"), Ok(Fraction::new(101, 10)));
+        assert_eq!(Fraction::from_str("3π"), Err("Unknown fraction symbol 'π'".to_string()));
+        assert!(Fraction::from_str("x½").is_err());
+        assert!(Fraction::from_str("1/2½").is_err());
+        assert!(Fraction::from_str("9223372036854775807½").is_err());
+        assert_eq!(Fraction::from_str("4611686018427387903½"), Ok(Fraction::new(i64::MAX, 2)));
+    }
+
+    #[test]
+    fn test_checked_from_str_error_kinds() {
+        let kind = |input: &str| Fraction::checked_from_str(input).unwrap_err().kind;
+        assert_eq!(Fraction::checked_from_str(" 2 1/4 "), Ok(Fraction::new(9, 4)));
+        assert_eq!(kind(""), ParseFractionErrorKind::Empty);
+        assert_eq!(kind("   "), ParseFractionErrorKind::Empty);
+        assert_eq!(kind("3/0"), ParseFractionErrorKind::ZeroDenominator);
+        assert_eq!(kind("1 1/0"), ParseFractionErrorKind::ZeroDenominator);
+        assert_eq!(kind("1/2/3"), ParseFractionErrorKind::TooManySlashes);
+        assert_eq!(kind("1//2"), ParseFractionErrorKind::TooManySlashes);
+        assert_eq!(kind("x/3"), ParseFractionErrorKind::InvalidNumber);
+        assert_eq!(kind("3/"), ParseFractionErrorKind::InvalidNumber);
+        assert_eq!(kind("2 -1/3"), ParseFractionErrorKind::InvalidNumber);
+        assert_eq!(kind("99999999999999999999/2"), ParseFractionErrorKind::InvalidNumber);
+        assert_eq!(kind("-9223372036854775808"), ParseFractionErrorKind::InvalidNumber);
+        assert_eq!(kind("1/-9223372036854775808"), ParseFractionErrorKind::InvalidNumber);
+        assert_eq!(
+            Fraction::checked_from_str("-9223372036854775807/9223372036854775807"),
+            Ok(Fraction::new(-1, 1))
+        );
+        assert_eq!(kind("5π"), ParseFractionErrorKind::UnknownSymbol('π'));
+        assert_eq!(Fraction::from_str("1/0"), Err("Denominator cannot be zero".to_string()));
+    }
 }
@@ -1,40 +1,251 @@
 // All Arithmetic operations
+mod barrett;
 mod bigint;
+mod montgomery;
+pub mod prelude;
 
-pub use crate::bigint::LargeInt;
+/// Conversions between bases 2 to 36.
+///
+/// ```
+/// use hello::base_conversion::convert_base;
+///
+/// assert_eq!(convert_base("ff", 16, 2).unwrap(), "11111111");
+/// ```
+pub mod base_conversion;
+
+/// Fractions with `i64` parts, kept in lowest terms.
+///
+/// ```
+/// use hello::fractions::Fraction;
+///
+/// let sum = Fraction::new(1, 2) + Fraction::new(1, 3);
+/// assert_eq!(sum.to_string(), "5/6");
+/// ```
+pub mod fractions;
+
+pub use crate::barrett::BarrettCtx;
+pub use crate::base_conversion::{convert_base, to_decimal, BaseConversionError};
+pub use crate::bigint::{LargeInt, ParseErrorKind, ParseLargeIntError};
+pub use crate::fractions::{Fraction, FractionError, ParseFractionError, ParseFractionErrorKind};
+pub use crate::montgomery::MontgomeryCtx;
+#[cfg(feature = "parallel")]
 use rayon::prelude::*; // Parallel processing using Rayon
 use std::cmp::Ordering;
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+
+/// Default operand size (in digits) below which multiplication skips Rayon.
+pub const DEFAULT_MULTIPLY_THRESHOLD: usize = 64;
+
+static MULTIPLY_THRESHOLD: AtomicUsize = AtomicUsize::new(DEFAULT_MULTIPLY_THRESHOLD);
+
+/// Sets the operand size (in digits) from which `multiply` uses the parallel
+/// algorithm instead of `multiply_seq`, for tuning on different hardware.
+/// The setting is process-wide and has no effect without the `parallel` feature.
+pub fn set_multiply_threshold(digits: usize) {
+    MULTIPLY_THRESHOLD.store(digits, AtomicOrdering::Relaxed);
+}
+
+/// Returns the current `multiply` algorithm threshold in digits.
+pub fn multiply_threshold() -> usize {
+    MULTIPLY_THRESHOLD.load(AtomicOrdering::Relaxed)
+}
+
+/// Operand size (in digits) from which division switches to Newton-Raphson
+/// reciprocals; measured to beat schoolbook division from a few hundred digits.
+const NEWTON_DIVISION_THRESHOLD: usize = 1000;
+
+/// Extra digits carried by the Newton division estimates to absorb truncation.
+const NEWTON_GUARD_DIGITS: usize = 16;
+
+/// Errors returned by the fallible arithmetic operations.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CalcError {
+    /// Integer exponentiation was asked for a negative power.
+    NegativeExponent,
+    /// The result would have more digits than the caller allowed.
+    ResultTooLarge,
+    /// Factorial was asked for a negative argument.
+    NegativeFactorial,
+    /// The argument does not fit the `usize` loop bound the operation needs.
+    ArgumentTooLarge,
+}
+
+impl fmt::Display for CalcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CalcError::NegativeExponent => write!(f, "Exponent must not be negative"),
+            CalcError::ResultTooLarge => write!(f, "Result exceeds the allowed number of digits"),
+            CalcError::NegativeFactorial => write!(f, "Factorial is not defined for negative numbers"),
+            CalcError::ArgumentTooLarge => write!(f, "Argument is too large"),
+        }
+    }
+}
+
+impl std::error::Error for CalcError {}
 
 /// Multiplies two LargeInt numbers.
-/// Uses parallel processing for efficient grid multiplication.
+/// Uses parallel processing for efficient grid multiplication, falling back to
+/// `multiply_seq` when either operand is below `multiply_threshold()` digits or
+/// the `parallel` feature is off.
 pub fn multiply(a: &LargeInt, b: &LargeInt) -> LargeInt {
-    let mut result = vec![0; a.digits.len() + b.digits.len()];
+    multiply_with_threshold(a, b, multiply_threshold())
+}
+
+/// Multiplies like `multiply`, but with an explicit parallel threshold in digits
+/// instead of the process-wide setting.
+#[cfg_attr(not(feature = "parallel"), allow(unused_variables))]
+pub fn multiply_with_threshold(a: &LargeInt, b: &LargeInt, threshold: usize) -> LargeInt {
+    #[cfg(feature = "parallel")]
+    if a.digits.len().min(b.digits.len()) >= threshold {
+        return multiply_parallel(a, b);
+    }
+
+    multiply_seq(a, b)
+}
+
+/// Grid multiplication with the columns spread across the Rayon thread pool.
+#[cfg(feature = "parallel")]
+fn multiply_parallel(a: &LargeInt, b: &LargeInt) -> LargeInt {
+    let len = a.digits.len() + b.digits.len();
 
-    a.digits.par_iter().enumerate().for_each(|(i, &da)| {
+    // Each column of the grid is summed independently, then carries are propagated.
+    let columns: Vec<u64> = (0..len)
+        .into_par_iter()
+        .map(|k| {
+            let lo = k.saturating_sub(b.digits.len() - 1);
+            let hi = k.min(a.digits.len() - 1);
+            (lo..=hi)
+                .map(|i| a.digits[i] as u64 * b.digits[k - i] as u64)
+                .sum()
+        })
+        .collect();
+
+    let mut result = Vec::with_capacity(len);
+    let mut carry = 0;
+    for column in columns {
+        let temp = column + carry;
+        result.push((temp % 10) as u8);
+        carry = temp / 10;
+    }
+
+    let mut product = LargeInt::new(a.sign * b.sign, result);
+    product.normalize();
+    product
+}
+
+/// Multiplies like `multiply`, but refuses to produce a result with more than
+/// `max_result_digits` digits. A product of m- and n-digit numbers has at least
+/// m + n - 1 digits, so oversized requests fail with `CalcError::ResultTooLarge`
+/// before anything is allocated.
+pub fn checked_mul(a: &LargeInt, b: &LargeInt, max_result_digits: usize) -> Result<LargeInt, CalcError> {
+    if a.is_zero() || b.is_zero() {
+        return Ok(LargeInt::zero());
+    }
+    if a.num_digits() + b.num_digits() - 1 > max_result_digits {
+        return Err(CalcError::ResultTooLarge);
+    }
+
+    // The product may still carry into one extra digit.
+    let result = multiply(a, b);
+    if result.num_digits() > max_result_digits {
+        return Err(CalcError::ResultTooLarge);
+    }
+    Ok(result)
+}
+
+/// Multiplies two LargeInt numbers with the sequential schoolbook method.
+/// Deterministic and free of thread-pool overhead.
+pub fn multiply_seq(a: &LargeInt, b: &LargeInt) -> LargeInt {
+    let mut result = vec![0u32; a.digits.len() + b.digits.len()];
+
+    for (i, &da) in a.digits.iter().enumerate() {
         let mut carry = 0;
         for (j, &db) in b.digits.iter().enumerate() {
-            let temp = result[i + j] + da * db + carry;
+            let temp = result[i + j] + da as u32 * db as u32 + carry;
             result[i + j] = temp % 10;
             carry = temp / 10;
         }
-        if carry > 0 {
-            result[i + b.digits.len()] += carry;
-        }
-    });
+        result[i + b.digits.len()] += carry;
+    }
 
-    let mut product = LargeInt::new(a.sign * b.sign, result);
-    product.normalize();
-    product
+    LargeInt::new(a.sign * b.sign, result.into_iter().map(|d| d as u8).collect())
+}
+
+/// Multiplies a list of LargeInt numbers together.
+/// Splits the slice in halves recursively so operands stay balanced in size.
+pub fn product_tree(values: &[LargeInt]) -> LargeInt {
+    match values.len() {
+        0 => LargeInt::one(),
+        1 => values[0].clone(),
+        len => {
+            let (left, right) = values.split_at(len / 2);
+            multiply(&product_tree(left), &product_tree(right))
+        }
+    }
 }
 
 /// Performs division and modulo operations simultaneously.
-/// Returns a tuple (quotient, remainder).
+/// Returns a tuple (quotient, remainder) using truncated division: the
+/// quotient is rounded toward zero and the remainder takes the sign of the
+/// dividend, so that `a == q * b + r` and `|r| < |b|`.
 /// Panics if division by zero is attempted.
 pub fn divide_and_modulo(a: &LargeInt, b: &LargeInt) -> (LargeInt, LargeInt) {
     if b.is_zero() {
         panic!("Division by zero is not allowed!");
     }
 
+    let (quotient, remainder) =
+        if a.digits.len() >= NEWTON_DIVISION_THRESHOLD && b.digits.len() >= NEWTON_DIVISION_THRESHOLD {
+            divide_newton(a, b)
+        } else {
+            divide_schoolbook(a, b)
+        };
+
+    (quotient, LargeInt::new(a.sign, remainder.digits))
+}
+
+/// Computes only the truncated quotient of `divide_and_modulo`.
+/// Prefer this when the remainder is not needed: divisors that fit in a `u64`
+/// take a single short-division pass with no remainder bookkeeping.
+/// Panics if division by zero is attempted.
+pub fn divide(a: &LargeInt, b: &LargeInt) -> LargeInt {
+    let divisor = match LargeInt::new(1, b.digits.clone()).to_u64() {
+        Some(0) => panic!("Division by zero is not allowed!"),
+        Some(divisor) => divisor as u128,
+        None => return divide_and_modulo(a, b).0,
+    };
+
+    let mut quotient = vec![0; a.digits.len()];
+    let mut remainder: u128 = 0;
+    for (i, &digit) in a.digits.iter().enumerate().rev() {
+        let current = remainder * 10 + digit as u128;
+        quotient[i] = (current / divisor) as u8;
+        remainder = current % divisor;
+    }
+    LargeInt::new(a.sign * b.sign, quotient)
+}
+
+/// Performs Euclidean division, matching `i64::div_euclid`/`rem_euclid`.
+/// Returns a tuple (quotient, remainder) with `0 <= r < |b|` and `a == q * b + r`.
+/// Panics if division by zero is attempted.
+pub fn div_euclid(a: &LargeInt, b: &LargeInt) -> (LargeInt, LargeInt) {
+    let (quotient, remainder) = divide_and_modulo(a, b);
+    if remainder.sign == 1 {
+        return (quotient, remainder);
+    }
+
+    let one = LargeInt::one();
+    if b.sign == 1 {
+        (quotient.subtract(&one), remainder.add(b))
+    } else {
+        (quotient.add(&one), remainder.subtract(b))
+    }
+}
+
+/// Long division, producing one quotient digit per dividend digit.
+/// The remainder is returned as a non-negative magnitude.
+fn divide_schoolbook(a: &LargeInt, b: &LargeInt) -> (LargeInt, LargeInt) {
     let mut quotient = Vec::new();
     let mut remainder = LargeInt::zero();
 
@@ -42,13 +253,20 @@ pub fn divide_and_modulo(a: &LargeInt, b: &LargeInt) -> (LargeInt, LargeInt) {
         remainder.digits.insert(0, digit);
         remainder.normalize();
 
-        let mut count = 0;
-        while remainder.compare_abs(b) != Ordering::Less {
+        // The remainder was below |b| before bringing down the digit, so it is
+        // now below 10 * |b| and at most nine subtractions are needed.
+        let mut count = 0u8;
+        while count < 9 && remainder.compare_abs(b) != Ordering::Less {
             remainder = remainder.subtract_same_sign(b);
             count += 1;
         }
+        debug_assert_eq!(
+            remainder.compare_abs(b),
+            Ordering::Less,
+            "quotient digit exceeded 9"
+        );
 
-        quotient.push(count as u8);
+        quotient.push(count);
     }
 
     quotient.reverse();
@@ -58,11 +276,122 @@ pub fn divide_and_modulo(a: &LargeInt, b: &LargeInt) -> (LargeInt, LargeInt) {
     )
 }
 
+/// Division by multiplying with a Newton-Raphson approximation of 1/b.
+/// Returns exactly the same (quotient, remainder) as the schoolbook method.
+fn divide_newton(a: &LargeInt, b: &LargeInt) -> (LargeInt, LargeInt) {
+    let a_abs = LargeInt::new(1, a.digits.clone());
+    let b_abs = LargeInt::new(1, b.digits.clone());
+
+    // Like schoolbook division, bring down the dividend a block of m digits at
+    // a time, so no step has more than an m-digit quotient.
+    let m = b.digits.len();
+    let mut blocks = Vec::new();
+    let mut remainder = LargeInt::zero();
+    let mut high = a.digits.len();
+    while high > 0 {
+        let low = high.saturating_sub(m);
+        let block = LargeInt::new(1, a_abs.digits[low..high].to_vec());
+        let (quotient, rest) = newton_divmod(&shift_left(&remainder, high - low).add(&block), &b_abs);
+        let mut digits = quotient.digits;
+        digits.resize(high - low, 0);
+        blocks.push(digits);
+        remainder = rest;
+        high = low;
+    }
+
+    let quotient: Vec<u8> = blocks.into_iter().rev().flatten().collect();
+    (LargeInt::new(a.sign * b.sign, quotient), remainder)
+}
+
+/// Divides positive magnitudes with a < b * 10^m, where b has m digits, by
+/// multiplying a's leading digits with an approximate reciprocal of b.
+fn newton_divmod(a: &LargeInt, b: &LargeInt) -> (LargeInt, LargeInt) {
+    let one = LargeInt::one();
+    if a.compare_abs(b) == Ordering::Less {
+        return (LargeInt::zero(), a.clone());
+    }
+
+    // The quotient has at most n - m + 1 digits, so only that many leading
+    // digits of a, plus guard digits, take part in the estimate.
+    let (n, m) = (a.digits.len(), b.digits.len());
+    let precision = n - m + NEWTON_GUARD_DIGITS;
+    let reciprocal = newton_reciprocal(b, m + precision);
+    let dropped = n.saturating_sub(precision + NEWTON_GUARD_DIGITS);
+    let leading = shift_right(a, dropped);
+
+    // The estimate is at most a few units off, so fix it up by stepping.
+    let mut quotient = shift_right(&multiply(&leading, &reciprocal), m + precision - dropped);
+    let mut remainder = a.subtract(&multiply(&quotient, b));
+    while remainder.sign == -1 {
+        quotient = quotient.subtract(&one);
+        remainder = remainder.add(b);
+    }
+    while remainder.compare_abs(b) != Ordering::Less {
+        quotient = quotient.add(&one);
+        remainder = remainder.subtract(b);
+    }
+
+    (quotient, remainder)
+}
+
+/// Approximates 10^k / b for positive b with k >= b's digit count, to within
+/// a few units. Each Newton step x <- x + x * (10^k - b * x) / 10^k doubles
+/// the correct digits, so the estimate is built from one at half precision,
+/// and only as many leading digits of b as the result needs are used.
+fn newton_reciprocal(b: &LargeInt, k: usize) -> LargeInt {
+    let m = b.digits.len();
+    let precision = k - m;
+    if m > precision + NEWTON_GUARD_DIGITS {
+        let dropped = m - precision - NEWTON_GUARD_DIGITS;
+        return newton_reciprocal(&shift_right(b, dropped), k - dropped);
+    }
+
+    let power = shift_left(&LargeInt::one(), k);
+    if precision <= 2 * NEWTON_GUARD_DIGITS {
+        // Only a few dozen digits are involved here.
+        return divide_schoolbook(&power, b).0;
+    }
+
+    let half = precision / 2 + NEWTON_GUARD_DIGITS;
+    let x = shift_left(&newton_reciprocal(b, m + half), precision - half);
+    let error = power.subtract(&multiply(b, &x));
+    x.add(&shift_right(&multiply(&x, &error), k))
+}
+
+/// Multiplies by 10^k by prepending zero digits.
+fn shift_left(n: &LargeInt, k: usize) -> LargeInt {
+    let mut digits = vec![0; k];
+    digits.extend_from_slice(&n.digits);
+    LargeInt::new(n.sign, digits)
+}
+
+/// Divides by 10^k, truncating, by dropping the lowest digits.
+fn shift_right(n: &LargeInt, k: usize) -> LargeInt {
+    if k >= n.digits.len() {
+        return LargeInt::zero();
+    }
+    LargeInt::new(n.sign, n.digits[k..].to_vec())
+}
+
 /// Exponentiates a LargeInt to the power of another LargeInt.
 /// Uses the binary exponentiation method for efficiency.
+/// Panics if the exponent is negative.
 pub fn exponentiate(base: &LargeInt, exp: &LargeInt) -> LargeInt {
+    checked_exponentiate(base, exp).unwrap_or_else(|err| panic!("{}", err))
+}
+
+/// Exponentiates a LargeInt to the power of another LargeInt.
+/// Returns `CalcError::NegativeExponent` instead of a non-integer result.
+pub fn checked_exponentiate(base: &LargeInt, exp: &LargeInt) -> Result<LargeInt, CalcError> {
+    if exp.sign == -1 {
+        return Err(CalcError::NegativeExponent);
+    }
     if exp.is_zero() {
-        return LargeInt::one();
+        return Ok(LargeInt::one());
+    }
+
+    if exp.is_one() {
+        return Ok(base.clone());
     }
 
     let mut result = LargeInt::one();
@@ -73,31 +402,1031 @@ pub fn exponentiate(base: &LargeInt, exp: &LargeInt) -> LargeInt {
         if exp.digits[0] % 2 == 1 {
             result = multiply(&result, &base);
         }
-        base = multiply(&base, &base);
-        exp = divide_and_modulo(&exp, &LargeInt::new(1, vec![2])).0;
+        base = base.square();
+        exp = divide(&exp, &LargeInt::two());
+    }
+
+    Ok(result)
+}
+
+/// Computes `base^exp mod modulus` by square-and-multiply, reducing after every step.
+/// The result lies in `0..|modulus|`. For many exponentiations with the same odd
+/// modulus, `MontgomeryCtx` is faster.
+/// Panics if the exponent is negative or the modulus is zero.
+pub fn modpow(base: &LargeInt, exp: &LargeInt, modulus: &LargeInt) -> LargeInt {
+    if exp.sign == -1 {
+        panic!("Exponent must be non-negative!");
+    }
+
+    let two = LargeInt::two();
+    let mut result = div_euclid(&LargeInt::one(), modulus).1;
+    let mut base = div_euclid(base, modulus).1;
+    let mut exp = exp.clone();
+
+    while !exp.is_zero() {
+        if exp.digits[0] % 2 == 1 {
+            result = div_euclid(&multiply(&result, &base), modulus).1;
+        }
+        base = div_euclid(&base.square(), modulus).1;
+        exp = divide(&exp, &two);
     }
 
     result
 }
 
+/// Exponentiates like `checked_exponentiate`, but refuses to produce a result
+/// with more than `max_result_digits` digits. The size is projected from
+/// `exp * log10(|base|)` before any multiplication happens, so inputs like
+/// `9^(9^9)` fail fast with `CalcError::ResultTooLarge`.
+pub fn exponentiate_limited(
+    base: &LargeInt,
+    exp: &LargeInt,
+    max_result_digits: usize,
+) -> Result<LargeInt, CalcError> {
+    if exp.sign == -1 {
+        return Err(CalcError::NegativeExponent);
+    }
+
+    // Bases 0 and ±1 never grow, whatever the exponent.
+    if base.num_digits() > 1 || base.digits[0] > 1 {
+        // log10(|base|) from its digit count and leading digits.
+        let leading_len = base.num_digits().min(15);
+        let leading: f64 = base.digits[base.num_digits() - leading_len..]
+            .iter()
+            .rev()
+            .fold(0.0, |acc, &d| acc * 10.0 + d as f64);
+        let log10_base = (leading.log10() - (leading_len - 1) as f64) + (base.num_digits() - 1) as f64;
+        let exp_value = exp.to_u64().map_or(f64::INFINITY, |e| e as f64);
+
+        // Allow one digit of slack for rounding in the estimate; the exact size is checked below.
+        let projected = (exp_value * log10_base).floor() + 1.0;
+        if projected > max_result_digits as f64 + 1.0 {
+            return Err(CalcError::ResultTooLarge);
+        }
+    }
+
+    let result = checked_exponentiate(base, exp)?;
+    if result.num_digits() > max_result_digits {
+        return Err(CalcError::ResultTooLarge);
+    }
+    Ok(result)
+}
+
+/// Computes the greatest common divisor of two LargeInts with Euclid's algorithm.
+/// When both magnitudes fit in a `u64`, native arithmetic is used instead.
+/// The result is always non-negative; `gcd(0, 0)` is 0.
+pub fn gcd(a: &LargeInt, b: &LargeInt) -> LargeInt {
+    let magnitude = |n: &LargeInt| LargeInt::new(1, n.digits.clone()).to_u64();
+    if let (Some(x), Some(y)) = (magnitude(a), magnitude(b)) {
+        return LargeInt::from_u64(num::integer::gcd(x, y));
+    }
+    gcd_euclid(a, b)
+}
+
+/// Euclid's algorithm on LargeInt magnitudes, without the `u64` fast path.
+fn gcd_euclid(a: &LargeInt, b: &LargeInt) -> LargeInt {
+    let mut a = LargeInt::new(1, a.digits.clone());
+    let mut b = LargeInt::new(1, b.digits.clone());
+    while !b.is_zero() {
+        if b.is_one() {
+            return b;
+        }
+        let remainder = divide_and_modulo(&a, &b).1;
+        a = b;
+        b = remainder;
+    }
+    a
+}
+
+/// Computes the greatest common divisor with Stein's binary algorithm, using
+/// only halving, subtraction and parity checks. Agrees with `gcd`.
+pub fn gcd_binary(a: &LargeInt, b: &LargeInt) -> LargeInt {
+    if a.is_zero() {
+        return LargeInt::new(1, b.digits.clone());
+    }
+    if b.is_zero() {
+        return LargeInt::new(1, a.digits.clone());
+    }
+
+    let (mut a, a_twos) = a.factor_out_twos();
+    let (mut b, b_twos) = b.factor_out_twos();
+
+    // Both values stay odd; their difference is even and shrinks the larger one.
+    loop {
+        if a.compare_abs(&b) == Ordering::Greater {
+            std::mem::swap(&mut a, &mut b);
+        }
+        b = b.subtract(&a);
+        if b.is_zero() {
+            break;
+        }
+        b = b.factor_out_twos().0;
+    }
+
+    for _ in 0..a_twos.min(b_twos) {
+        a = a.add(&a);
+    }
+    a
+}
+
 /// Computes the factorial of a LargeInt.
-/// Uses parallel reduction for efficient computation.
+/// Uses parallel reduction for efficient computation, or a sequential product
+/// tree when the `parallel` feature is off.
+/// Panics if the argument is negative or does not fit in a `usize`.
 pub fn factorial(n: &LargeInt) -> LargeInt {
+    checked_factorial(n).unwrap_or_else(|err| panic!("{}", err))
+}
+
+/// Computes the factorial of a LargeInt.
+/// Returns `CalcError::NegativeFactorial` for negative arguments and
+/// `CalcError::ArgumentTooLarge` for arguments above `usize::MAX`.
+pub fn checked_factorial(n: &LargeInt) -> Result<LargeInt, CalcError> {
     if n.is_zero() {
-        return LargeInt::one();
+        return Ok(LargeInt::one());
     }
 
     if n.sign == -1 {
-        panic!("Factorial is not defined for negative numbers!");
+        return Err(CalcError::NegativeFactorial);
     }
 
-    let one = LargeInt::one();
-    let range: Vec<LargeInt> = (1..=n.to_string().parse::<usize>().unwrap())
-        .map(|x| LargeInt::parse(&x.to_string()))
+    let bound = n.to_usize().ok_or(CalcError::ArgumentTooLarge)?;
+    let range: Vec<LargeInt> = (1..=bound)
+        .map(|x| LargeInt::from_u64(x as u64))
         .collect();
 
-    range
-        .par_iter()
-        .cloned()
-        .reduce(|| one.clone(), |acc, x| multiply(&acc, &x))
+    #[cfg(feature = "parallel")]
+    {
+        let one = LargeInt::one();
+        Ok(range
+            .par_iter()
+            .cloned()
+            .reduce(|| one.clone(), |acc, x| multiply(&acc, &x)))
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    Ok(product_tree(&range))
+}
+
+/// Computes the binomial coefficient C(n, k) as a running product
+/// `C(n, i) = C(n, i - 1) * (n - k + i) / i`, where every division is exact.
+/// Returns 0 when k exceeds n.
+pub fn binomial(n: u64, k: u64) -> LargeInt {
+    if k > n {
+        return LargeInt::zero();
+    }
+
+    let k = k.min(n - k);
+    let mut result = LargeInt::one();
+    for i in 1..=k {
+        result = multiply(&result, &LargeInt::from_u64(n - k + i));
+        result = divide(&result, &LargeInt::from_u64(i));
+    }
+    result
+}
+
+/// Computes the nth Catalan number `C(2n, n) / (n + 1)`; `catalan(0)` is 1.
+/// Panics if 2n does not fit in a `u64`.
+pub fn catalan(n: u64) -> LargeInt {
+    let double = n.checked_mul(2).expect("Catalan index is too large!");
+    divide(&binomial(double, n), &LargeInt::from_u64(n + 1))
+}
+
+/// Computes the integer square root of a LargeInt together with the remainder.
+/// Returns (r, n - r²), found digit pair by digit pair so r² is never recomputed.
+/// Panics if the input is negative.
+pub fn sqrt_rem(n: &LargeInt) -> (LargeInt, LargeInt) {
+    if n.sign == -1 {
+        panic!("Square root is not defined for negative numbers!");
+    }
+
+    let twenty = LargeInt::small(20);
+    let mut root = LargeInt::zero();
+    let mut remainder = LargeInt::zero();
+
+    let mut digits = n.digits.clone();
+    if digits.len() % 2 == 1 {
+        digits.push(0);
+    }
+
+    for pair in digits.chunks(2).rev() {
+        remainder.digits.insert(0, pair[1]);
+        remainder.digits.insert(0, pair[0]);
+        remainder.normalize();
+
+        // Largest x with (20 * root + x) * x <= remainder.
+        let base = multiply(&root, &twenty);
+        let mut x = 9;
+        let mut step = LargeInt::zero();
+        while x > 0 {
+            let digit = LargeInt::small(x);
+            step = multiply(&base.add(&digit), &digit);
+            if step.compare_abs(&remainder) != Ordering::Greater {
+                break;
+            }
+            x -= 1;
+        }
+        if x == 0 {
+            step = LargeInt::zero();
+        }
+
+        remainder = remainder.subtract(&step);
+        root.digits.insert(0, x);
+        root.normalize();
+    }
+
+    (root, remainder)
+}
+
+/// Computes the integer square root (floor) of a LargeInt.
+/// This stays on the digit-pair `sqrt_rem` rather than `binary_search_int`:
+/// bisection squares a full-size candidate once per bit of the root, which
+/// is about 100 times slower already at 2000 digits.
+/// Panics if the input is negative.
+pub fn isqrt(n: &LargeInt) -> LargeInt {
+    sqrt_rem(n).0
+}
+
+/// Returns the square root truncated to `decimals` places, e.g. `"1.41"` for 2,
+/// as the integer square root of `n * 10^(2 * decimals)` with a decimal point inserted.
+/// Panics if the input is negative.
+pub fn sqrt_decimals(n: &LargeInt, decimals: usize) -> String {
+    let root = isqrt(&multiply(n, &LargeInt::pow10(2 * decimals))).to_string();
+    if decimals == 0 {
+        return root;
+    }
+
+    let padded = format!("{:0>width$}", root, width = decimals + 1);
+    let (whole, fraction) = padded.split_at(padded.len() - decimals);
+    format!("{}.{}", whole, fraction)
+}
+
+/// Computes the integer k-th root (floor) of a LargeInt by bisection.
+/// Panics if the input is negative or k is zero.
+pub fn nth_root(n: &LargeInt, k: u32) -> LargeInt {
+    if n.sign == -1 {
+        panic!("Roots are not computed for negative numbers!");
+    }
+    if k == 0 {
+        panic!("The zeroth root is not defined!");
+    }
+
+    let exp = LargeInt::from_u64(k as u64);
+    // The root has at most ceil(digits / k) digits.
+    let root_digits = n.digits.len().div_ceil(k as usize);
+    binary_search_int(&LargeInt::zero(), &LargeInt::pow10(root_digits), |x| {
+        exponentiate(x, &exp).compare_abs(n) != Ordering::Greater
+    })
+}
+
+/// Bisects for the boundary of a monotone predicate: returns the largest x in
+/// `lo..hi` for which `pred` holds. `pred(lo)` must be true, and once `pred`
+/// turns false it must stay false up to `hi`, which is never tested.
+pub fn binary_search_int(lo: &LargeInt, hi: &LargeInt, pred: impl Fn(&LargeInt) -> bool) -> LargeInt {
+    let two = LargeInt::two();
+    let mut lo = lo.clone();
+    let mut hi = hi.clone();
+
+    // Invariant: pred(lo) holds and pred(hi) does not.
+    while hi.subtract(&lo) > 1 {
+        let mid = div_euclid(&lo.add(&hi), &two).0;
+        if pred(&mid) {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+
+    lo
+}
+
+/// Bases for the Miller-Rabin test; together they are deterministic below 3.3 * 10^24.
+const MILLER_RABIN_BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// Tests primality with trial division by small primes followed by Miller-Rabin
+/// rounds over fixed bases. The answer is exact below 3.3 * 10^24; above that a
+/// composite is reported as prime with negligible probability.
+/// Values below 2, including negatives, are not prime.
+pub fn is_probable_prime(n: &LargeInt) -> bool {
+    if n.sign == -1 || n.compare_abs(&LargeInt::two()) == Ordering::Less {
+        return false;
+    }
+    for &p in &MILLER_RABIN_BASES {
+        if n.rem_small(p) == 0 {
+            return *n == p as i64;
+        }
+    }
+
+    let n_minus_one = n.subtract(&LargeInt::one());
+    let (odd, twos) = n_minus_one.factor_out_twos();
+    let ctx = MontgomeryCtx::new(n);
+    MILLER_RABIN_BASES.iter().all(|&a| {
+        let mut x = ctx.pow(&LargeInt::from(a as i64), &odd);
+        if x.is_one() || x.compare_abs(&n_minus_one) == Ordering::Equal {
+            return true;
+        }
+        for _ in 1..twos {
+            x = div_euclid(&x.square(), n).1;
+            if x.compare_abs(&n_minus_one) == Ordering::Equal {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+/// Returns the smallest prime greater than n, scanning odd candidates.
+pub fn next_prime(n: &LargeInt) -> LargeInt {
+    if *n < 2 {
+        return LargeInt::two();
+    }
+
+    let two = LargeInt::two();
+    let mut candidate = n.add(&LargeInt::one());
+    if candidate.digits[0].is_multiple_of(2) {
+        candidate = candidate.add(&LargeInt::one());
+    }
+    while !is_probable_prime(&candidate) {
+        candidate = candidate.add(&two);
+    }
+    candidate
+}
+
+/// Returns the largest prime less than n, scanning odd candidates,
+/// or `None` when n is 2 or less.
+pub fn prev_prime(n: &LargeInt) -> Option<LargeInt> {
+    if *n <= 2 {
+        return None;
+    }
+    if *n == 3 {
+        return Some(LargeInt::two());
+    }
+
+    let two = LargeInt::two();
+    let mut candidate = n.subtract(&LargeInt::one());
+    if candidate.digits[0].is_multiple_of(2) {
+        candidate = candidate.subtract(&LargeInt::one());
+    }
+    // 3 is prime, so the scan always stops.
+    while !is_probable_prime(&candidate) {
+        candidate = candidate.subtract(&two);
+    }
+    Some(candidate)
+}
+
+/// Counts the primes less than or equal to n with a sieve of Eratosthenes
+/// over the odd numbers. Memory grows linearly with n, so this is meant for
+/// bounds up to about 10^8.
+pub fn prime_pi(n: u64) -> u64 {
+    if n < 2 {
+        return 0;
+    }
+
+    // composite[i] marks 2i + 1; index 0 stands for 1, which is not counted.
+    let len = (n as usize).div_ceil(2);
+    let mut composite = vec![false; len];
+    let mut i = 1;
+    while (2 * i + 1) * (2 * i + 1) <= n as usize {
+        if !composite[i] {
+            let p = 2 * i + 1;
+            for j in (p * p / 2..len).step_by(p) {
+                composite[j] = true;
+            }
+        }
+        i += 1;
+    }
+
+    1 + composite[1..].iter().filter(|&&c| !c).count() as u64
+}
+
+/// Splits off the prime factors of |n| up to `limit` by trial division.
+/// Returns the (prime, multiplicity) pairs found, in ascending order, and the
+/// remaining cofactor, which is 1 when |n| factors completely below `limit`.
+/// Panics if n is zero.
+pub fn trial_division(n: &LargeInt, limit: u64) -> (Vec<(u64, u32)>, LargeInt) {
+    if n.is_zero() {
+        panic!("Cannot factor zero!");
+    }
+
+    let mut factors = Vec::new();
+    let mut rest = LargeInt::new(1, n.digits.clone());
+    let mut candidate = 2;
+    while candidate <= limit && !rest.is_one() {
+        let mut multiplicity = 0;
+        while rest.rem_small(candidate) == 0 {
+            rest = divide(&rest, &LargeInt::from_u64(candidate));
+            multiplicity += 1;
+        }
+        if multiplicity > 0 {
+            factors.push((candidate, multiplicity));
+        }
+        candidate += if candidate == 2 { 1 } else { 2 };
+    }
+
+    (factors, rest)
+}
+
+/// Returns the multiplicative order of a modulo |m|: the smallest k > 0 with
+/// `a^k ≡ 1 (mod m)`, or `None` when a and m are not coprime.
+/// Powers are stepped through one multiplication at a time, so the cost grows
+/// with the order itself, which is at most φ(m).
+/// Panics if the modulus is zero.
+pub fn multiplicative_order(a: &LargeInt, m: &LargeInt) -> Option<LargeInt> {
+    if m.is_zero() {
+        panic!("Modulus must be non-zero!");
+    }
+    if !gcd(a, m).is_one() {
+        return None;
+    }
+
+    let one = div_euclid(&LargeInt::one(), m).1;
+    let base = div_euclid(a, m).1;
+    let mut power = base.clone();
+    let mut order = LargeInt::one();
+    while power != one {
+        power = div_euclid(&multiply(&power, &base), m).1;
+        order = order.add(&LargeInt::one());
+    }
+    Some(order)
+}
+
+/// Detects whether a LargeInt is a perfect power `base^exp` with exp >= 2.
+/// Returns the smallest such base with its exponent, or `None`.
+/// Only values greater than one are considered.
+pub fn is_perfect_power(n: &LargeInt) -> Option<(LargeInt, u32)> {
+    let one = LargeInt::one();
+    if n.sign == -1 || n.compare_abs(&one) != Ordering::Greater {
+        return None;
+    }
+
+    // Trying the largest exponent first yields the smallest base.
+    for k in (2..=n.bit_length() as u32).rev() {
+        let root = nth_root(n, k);
+        let power = exponentiate(&root, &LargeInt::from_u64(k as u64));
+        if power.compare_abs(n) == Ordering::Equal {
+            return Some((root, k));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    /// Runs `f` once and returns its result with the elapsed wall-clock time,
+    /// for the `#[ignore]`d timing tests.
+    fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+        let start = Instant::now();
+        let result = f();
+        (result, start.elapsed())
+    }
+
+    /// Deterministic pseudo-random digit strings for comparing algorithms.
+    fn random_digits(seed: &mut u64, len: usize) -> String {
+        let mut digits = String::with_capacity(len);
+        for i in 0..len {
+            *seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            let digit = ((*seed >> 33) % 10) as u8;
+            digits.push((b'0' + if i == 0 { digit.max(1) } else { digit }) as char);
+        }
+        digits
+    }
+
+    #[test]
+    fn test_product_tree_matches_fold() {
+        let values: Vec<LargeInt> = ["12", "-345", "6789", "1", "98765432109876543210", "7"]
+            .iter()
+            .map(|s| LargeInt::parse(s))
+            .collect();
+        let expected = values.iter().fold(LargeInt::one(), |acc, x| multiply(&acc, x));
+        assert_eq!(product_tree(&values).to_string(), expected.to_string());
+        assert_eq!(product_tree(&[]).to_string(), "1");
+    }
+
+    #[test]
+    fn test_product_tree_many_factors() {
+        let values: Vec<LargeInt> = (1..=300).map(LargeInt::from).collect();
+        let fold = values.iter().fold(LargeInt::one(), |acc, x| multiply(&acc, x));
+        let tree = product_tree(&values);
+        assert_eq!(tree, fold);
+        assert_eq!(tree.digits.len(), 615);
+    }
+
+    #[test]
+    #[ignore = "timing comparison; run with --ignored in release mode"]
+    fn test_product_tree_timing() {
+        let values: Vec<LargeInt> = (1..=3000).map(LargeInt::from).collect();
+        let (tree, tree_time) = timed(|| product_tree(&values));
+        let (fold, fold_time) = timed(|| values.iter().fold(LargeInt::one(), |acc, x| multiply(&acc, x)));
+        assert_eq!(tree, fold);
+        assert!(tree_time < fold_time, "{:?} vs {:?}", tree_time, fold_time);
+    }
+
+    #[test]
+    fn test_sqrt_rem() {
+        let (root, rem) = sqrt_rem(&LargeInt::parse("1000"));
+        assert_eq!(root.to_string(), "31");
+        assert_eq!(rem.to_string(), "39");
+
+        let (root, rem) = sqrt_rem(&LargeInt::zero());
+        assert_eq!((root.to_string(), rem.to_string()), ("0".to_string(), "0".to_string()));
+
+        for s in ["1", "2", "99", "100", "123456789012345678901234567890", "999999999999"] {
+            let n = LargeInt::parse(s);
+            let (root, rem) = sqrt_rem(&n);
+            assert_eq!(multiply(&root, &root).add(&rem).to_string(), n.to_string());
+            let next = root.add(&LargeInt::one());
+            assert_eq!(multiply(&next, &next).compare_abs(&n), Ordering::Greater);
+        }
+        assert_eq!(isqrt(&LargeInt::parse("10000000000")).to_string(), "100000");
+    }
+
+    #[test]
+    fn test_sqrt_decimals() {
+        assert!(sqrt_decimals(&LargeInt::two(), 10).starts_with("1.4142135623"));
+        assert_eq!(sqrt_decimals(&LargeInt::two(), 0), "1");
+        assert_eq!(sqrt_decimals(&LargeInt::parse("16"), 3), "4.000");
+        assert_eq!(sqrt_decimals(&LargeInt::zero(), 2), "0.00");
+        let long = sqrt_decimals(&LargeInt::parse("3"), 50);
+        assert_eq!(long, "1.73205080756887729352744634150587236694280525381038");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sqrt_rem_negative() {
+        sqrt_rem(&LargeInt::parse("-4"));
+    }
+
+    #[test]
+    fn test_newton_division_matches_schoolbook() {
+        let mut seed = 42;
+        for i in 0..20 {
+            let a = LargeInt::parse(&random_digits(&mut seed, 350 + i * 17));
+            let b = LargeInt::parse(&random_digits(&mut seed, 300 + i * 5));
+            let (q1, r1) = divide_schoolbook(&a, &b);
+            let (q2, r2) = divide_newton(&a, &b);
+            assert_eq!(q1.to_string(), q2.to_string());
+            assert_eq!(r1.to_string(), r2.to_string());
+        }
+
+        for (a_len, b_len) in [(1500, 250), (900, 899), (64, 1)] {
+            let a = LargeInt::parse(&random_digits(&mut seed, a_len));
+            let b = LargeInt::parse(&random_digits(&mut seed, b_len));
+            assert_eq!(divide_newton(&a, &b), divide_schoolbook(&a, &b));
+        }
+        // Blocks of zero digits in the dividend.
+        let a = LargeInt::pow10(1200);
+        let b = LargeInt::parse(&random_digits(&mut seed, 300));
+        assert_eq!(divide_newton(&a, &b), divide_schoolbook(&a, &b));
+
+        let a = LargeInt::parse("123456789");
+        let b = LargeInt::parse("-1234567890123");
+        assert_eq!(divide_newton(&a, &b).1.to_string(), divide_schoolbook(&a, &b).1.to_string());
+    }
+
+    #[test]
+    fn test_schoolbook_quotient_digits_at_boundary() {
+        // Every step of these divisions needs the maximum quotient digit of 9.
+        let cases = [
+            ("99999999999999999999", "1"),
+            ("99999999999999999999", "-11"),
+            ("999999999999999999999999", "100000000001"),
+            ("-89999999999999999999991", "9999999999"),
+            ("1000000000000000000000", "1000000000000000000001"),
+        ];
+        for (a, b) in cases {
+            let (a, b) = (LargeInt::parse(a), LargeInt::parse(b));
+            let (q, r) = divide_schoolbook(&a, &b);
+            assert!(q.decimal_digits().iter().all(|&d| d <= 9));
+            assert_eq!(r.compare_abs(&b), Ordering::Less);
+            let rebuilt = multiply(&q, &b).add(&LargeInt::new(a.sign, r.digits.clone()));
+            assert_eq!(rebuilt.to_string(), a.to_string());
+        }
+    }
+
+    #[test]
+    fn test_divide_matches_divide_and_modulo() {
+        let mut seed = 21;
+        for (a_len, b_len) in [(1, 1), (30, 1), (30, 19), (30, 20), (60, 25), (5, 12)] {
+            for signs in ["", "-"] {
+                let a = LargeInt::parse(&format!("{}{}", signs, random_digits(&mut seed, a_len)));
+                let b = LargeInt::parse(&random_digits(&mut seed, b_len));
+                assert_eq!(divide(&a, &b), divide_and_modulo(&a, &b).0);
+                let negative_b = LargeInt::new(-1, b.digits.clone());
+                assert_eq!(divide(&a, &negative_b), divide_and_modulo(&a, &negative_b).0);
+            }
+        }
+        assert_eq!(divide(&LargeInt::parse("-7"), &LargeInt::two()).to_string(), "-3");
+    }
+
+    #[test]
+    #[should_panic(expected = "Division by zero is not allowed!")]
+    fn test_divide_by_zero() {
+        divide(&LargeInt::one(), &LargeInt::zero());
+    }
+
+    #[test]
+    #[ignore = "timing comparison; run with --ignored in release mode"]
+    fn test_newton_division_timing() {
+        let mut seed = 7;
+        let a = LargeInt::parse(&random_digits(&mut seed, 2400));
+        let b = LargeInt::parse(&random_digits(&mut seed, 1200));
+        let (schoolbook, schoolbook_time) = timed(|| divide_schoolbook(&a, &b));
+        let (newton, newton_time) = timed(|| divide_newton(&a, &b));
+        assert_eq!(schoolbook, newton);
+        assert!(newton_time < schoolbook_time, "{:?} vs {:?}", newton_time, schoolbook_time);
+
+        // A quotient much longer than the divisor is produced block by block.
+        let a = LargeInt::parse(&random_digits(&mut seed, 20000));
+        let (schoolbook, schoolbook_time) = timed(|| divide_schoolbook(&a, &b));
+        let (newton, newton_time) = timed(|| divide_newton(&a, &b));
+        assert_eq!(schoolbook, newton);
+        assert!(newton_time < schoolbook_time, "{:?} vs {:?}", newton_time, schoolbook_time);
+    }
+
+    #[test]
+    fn test_checked_mul() {
+        let a = LargeInt::parse("999");
+        let b = LargeInt::parse("-99");
+        assert_eq!(checked_mul(&a, &b, 5).unwrap().to_string(), "-98901");
+        assert_eq!(checked_mul(&a, &b, 4).unwrap_err(), CalcError::ResultTooLarge);
+        assert_eq!(checked_mul(&LargeInt::parse("100"), &LargeInt::parse("10"), 4).unwrap().to_string(), "1000");
+        assert_eq!(checked_mul(&LargeInt::zero(), &a, 1).unwrap().to_string(), "0");
+
+        // Two million-digit factors are rejected from their sizes alone.
+        let huge = LargeInt::pow10(1_000_000);
+        assert_eq!(checked_mul(&huge, &huge, 1_000_000).unwrap_err(), CalcError::ResultTooLarge);
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    #[ignore = "timing comparison on multi-million-digit operands; run with --ignored in release mode"]
+    fn test_parallel_addition_timing() {
+        let mut seed = 17;
+        let a = LargeInt::parse(&random_digits(&mut seed, 3_000_000));
+        let b = LargeInt::parse(&random_digits(&mut seed, 2_000_000));
+        let (sequential, sequential_time) = timed(|| a.add_same_sign_seq(&b));
+        let (parallel, parallel_time) = timed(|| a.add(&b));
+        assert_eq!(parallel, sequential);
+        assert!(parallel_time < sequential_time, "{:?} vs {:?}", parallel_time, sequential_time);
+
+        // A long run of nines forces carries across every chunk boundary.
+        let nines = LargeInt::parse(&"9".repeat(2_500_000));
+        assert_eq!(nines.add(&LargeInt::one()), LargeInt::pow10(2_500_000));
+    }
+
+    #[test]
+    fn test_multiply_threshold() {
+        let mut seed = 5;
+        let a = LargeInt::parse(&random_digits(&mut seed, 300));
+        let b = LargeInt::parse(&random_digits(&mut seed, 200));
+        let expected = multiply_seq(&a, &b).to_string();
+
+        for threshold in [0, 1, 199, 200, 201, DEFAULT_MULTIPLY_THRESHOLD, usize::MAX] {
+            assert_eq!(multiply_with_threshold(&a, &b, threshold).to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_square_matches_multiply() {
+        let mut seed = 11;
+        for len in [1, 2, 3, 10, 63, 64, 65, 200] {
+            let x = LargeInt::parse(&random_digits(&mut seed, len));
+            assert_eq!(x.square().to_string(), multiply(&x, &x).to_string());
+            let negated = LargeInt::parse(&format!("-{}", x));
+            assert_eq!(negated.square().to_string(), multiply(&x, &x).to_string());
+        }
+        assert_eq!(LargeInt::zero().square().to_string(), "0");
+        assert_eq!(LargeInt::parse("999").square().to_string(), "998001");
+    }
+
+    #[test]
+    #[ignore = "timing comparison; run with --ignored in release mode"]
+    fn test_square_timing() {
+        let mut seed = 3;
+        let x = LargeInt::parse(&random_digits(&mut seed, 3000));
+        let (squared, square_time) = timed(|| x.square());
+        let (product, multiply_time) = timed(|| multiply_seq(&x, &x));
+        assert_eq!(squared, product);
+        assert!(square_time < multiply_time, "{:?} vs {:?}", square_time, multiply_time);
+    }
+
+    #[test]
+    fn test_modpow() {
+        let pow = |b: &str, e: &str, m: &str| {
+            modpow(&LargeInt::parse(b), &LargeInt::parse(e), &LargeInt::parse(m)).to_string()
+        };
+        assert_eq!(pow("4", "13", "497"), "445");
+        assert_eq!(pow("-2", "3", "5"), "2");
+        assert_eq!(pow("7", "0", "1"), "0");
+        assert_eq!(pow("2", "100", "1000000007"), "976371285");
+    }
+
+    #[test]
+    fn test_is_probable_prime() {
+        let primes: Vec<i64> = (0..200).filter(|&n| is_probable_prime(&LargeInt::from(n))).collect();
+        let expected: Vec<i64> = (0..200)
+            .filter(|&n: &i64| n >= 2 && (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0))
+            .collect();
+        assert_eq!(primes, expected);
+
+        assert!(is_probable_prime(&LargeInt::parse("1000000007")));
+        assert!(is_probable_prime(&LargeInt::parse("170141183460469231731687303715884105727")));
+        // Strong pseudoprime to bases 2, 3, 5 and 7.
+        assert!(!is_probable_prime(&LargeInt::parse("3215031751")));
+        assert!(!is_probable_prime(&LargeInt::parse("-7")));
+    }
+
+    #[test]
+    fn test_next_and_prev_prime() {
+        assert_eq!(next_prime(&LargeInt::parse("100")).to_string(), "101");
+        assert_eq!(prev_prime(&LargeInt::parse("100")).unwrap().to_string(), "97");
+        assert!(prev_prime(&LargeInt::parse("2")).is_none());
+        assert_eq!(prev_prime(&LargeInt::parse("3")).unwrap().to_string(), "2");
+        assert_eq!(next_prime(&LargeInt::parse("-5")).to_string(), "2");
+        assert_eq!(next_prime(&LargeInt::parse("2")).to_string(), "3");
+        assert_eq!(next_prime(&LargeInt::parse("1000000000")).to_string(), "1000000007");
+    }
+
+    #[test]
+    fn test_prime_pi() {
+        assert_eq!(prime_pi(0), 0);
+        assert_eq!(prime_pi(1), 0);
+        assert_eq!(prime_pi(2), 1);
+        assert_eq!(prime_pi(3), 2);
+        assert_eq!(prime_pi(10), 4);
+        assert_eq!(prime_pi(100), 25);
+        assert_eq!(prime_pi(1000), 168);
+        assert_eq!(prime_pi(1_000_000), 78498);
+
+        for n in 0..200u64 {
+            let expected = (0..=n).filter(|&k| is_probable_prime(&LargeInt::from_u64(k))).count();
+            assert_eq!(prime_pi(n), expected as u64, "pi({})", n);
+        }
+    }
+
+    #[test]
+    fn test_gcd_fast_path_matches_euclid() {
+        let mut seed = 99;
+        for len in [1, 5, 10, 19, 20] {
+            for _ in 0..20 {
+                let a = LargeInt::parse(&random_digits(&mut seed, len));
+                let b = LargeInt::parse(&random_digits(&mut seed, len.max(3) - 2));
+                let common = LargeInt::parse(&random_digits(&mut seed, 1));
+                let (a, b) = (multiply(&a, &common), multiply(&b, &common));
+                assert_eq!(gcd(&a, &b), gcd_euclid(&a, &b));
+            }
+        }
+        let negative = LargeInt::parse("-18446744073709551615");
+        assert_eq!(gcd(&negative, &LargeInt::parse("5")).to_string(), "5");
+        assert_eq!(gcd(&LargeInt::zero(), &LargeInt::zero()).to_string(), "0");
+    }
+
+    #[test]
+    #[ignore = "timing comparison; run with --ignored in release mode"]
+    fn test_gcd_small_pairs_timing() {
+        let mut seed = 1234;
+        let pairs: Vec<(LargeInt, LargeInt)> = (0..2000)
+            .map(|_| {
+                let a = LargeInt::parse(&random_digits(&mut seed, 12));
+                let b = LargeInt::parse(&random_digits(&mut seed, 9));
+                (a, b)
+            })
+            .collect();
+
+        let (fast, fast_time) = timed(|| pairs.iter().map(|(a, b)| gcd(a, b)).collect::<Vec<_>>());
+        let (slow, slow_time) = timed(|| pairs.iter().map(|(a, b)| gcd_euclid(a, b)).collect::<Vec<_>>());
+        assert_eq!(fast, slow);
+        assert!(fast_time < slow_time, "{:?} vs {:?}", fast_time, slow_time);
+    }
+
+    #[test]
+    fn test_trial_division() {
+        let (factors, rest) = trial_division(&LargeInt::parse("-360"), 100);
+        assert_eq!(factors, vec![(2, 3), (3, 2), (5, 1)]);
+        assert_eq!(rest.to_string(), "1");
+
+        let (factors, rest) = trial_division(&LargeInt::parse("2000000014"), 10);
+        assert_eq!(factors, vec![(2, 1)]);
+        assert_eq!(rest.to_string(), "1000000007");
+    }
+
+    #[test]
+    fn test_multiplicative_order() {
+        let order = |a: i64, m: i64| multiplicative_order(&LargeInt::from(a), &LargeInt::from(m));
+        assert_eq!(order(2, 7), Some(LargeInt::from(3)));
+        assert_eq!(order(3, 7), Some(LargeInt::from(6)));
+        assert_eq!(order(1, 7), Some(LargeInt::from(1)));
+        assert_eq!(order(-1, 7), Some(LargeInt::from(2)));
+        assert_eq!(order(10, -7), Some(LargeInt::from(6)));
+        assert_eq!(order(5, 1), Some(LargeInt::from(1)));
+        assert_eq!(order(10, 1000003), Some(LargeInt::from(166667)));
+        assert_eq!(order(4, 6), None);
+        assert_eq!(order(0, 7), None);
+
+        let (a, m) = (LargeInt::from(7), LargeInt::from(1000));
+        let k = multiplicative_order(&a, &m).unwrap();
+        assert!(modpow(&a, &k, &m).is_one());
+    }
+
+    #[test]
+    #[should_panic(expected = "Modulus must be non-zero!")]
+    fn test_multiplicative_order_zero_modulus() {
+        multiplicative_order(&LargeInt::from(3), &LargeInt::zero());
+    }
+
+    #[test]
+    fn test_binomial() {
+        assert_eq!(binomial(0, 0), LargeInt::one());
+        assert_eq!(binomial(5, 2), LargeInt::from(10));
+        assert_eq!(binomial(5, 5), LargeInt::one());
+        assert_eq!(binomial(3, 4), LargeInt::zero());
+        assert_eq!(binomial(64, 32), LargeInt::parse("1832624140942590534"));
+        assert_eq!(binomial(100, 50), LargeInt::parse("100891344545564193334812497256"));
+    }
+
+    #[test]
+    fn test_catalan() {
+        let expected = [1, 1, 2, 5, 14, 42, 132, 429, 1430, 4862];
+        for (n, &value) in expected.iter().enumerate() {
+            assert_eq!(catalan(n as u64), LargeInt::from(value), "C_{}", n);
+        }
+        assert_eq!(catalan(20), LargeInt::from(6564120420));
+        assert_eq!(catalan(100), LargeInt::parse("896519947090131496687170070074100632420837521538745909320"));
+    }
+
+    #[test]
+    fn test_nth_root() {
+        assert_eq!(nth_root(&LargeInt::parse("1000"), 3).to_string(), "10");
+        assert_eq!(nth_root(&LargeInt::parse("999"), 3).to_string(), "9");
+        assert_eq!(nth_root(&LargeInt::parse("1"), 5).to_string(), "1");
+        assert_eq!(nth_root(&LargeInt::zero(), 2).to_string(), "0");
+        assert_eq!(nth_root(&LargeInt::parse("1000"), 2).to_string(), "31");
+    }
+
+    #[test]
+    fn test_binary_search_int() {
+        let limit = LargeInt::from(1000);
+        let root = binary_search_int(&LargeInt::zero(), &limit, |x| multiply(x, x).compare_abs(&limit) != Ordering::Greater);
+        assert_eq!(root, LargeInt::from(31));
+
+        // Negative ranges bisect with floored midpoints.
+        let found = binary_search_int(&LargeInt::from(-50), &LargeInt::from(50), |x| *x < -7);
+        assert_eq!(found, LargeInt::from(-8));
+        let single = binary_search_int(&LargeInt::from(4), &LargeInt::from(5), |_| true);
+        assert_eq!(single, LargeInt::from(4));
+
+        let big = LargeInt::parse("123456789012345678901234567890");
+        let root = binary_search_int(&LargeInt::zero(), &big, |x| x.square().compare_abs(&big) != Ordering::Greater);
+        assert_eq!(root, isqrt(&big));
+    }
+
+    #[test]
+    fn test_is_perfect_power() {
+        let (base, exp) = is_perfect_power(&LargeInt::parse("1000000")).unwrap();
+        assert_eq!((base.to_string(), exp), ("10".to_string(), 6));
+
+        let (base, exp) = is_perfect_power(&LargeInt::parse("1024")).unwrap();
+        assert_eq!((base.to_string(), exp), ("2".to_string(), 10));
+
+        assert!(is_perfect_power(&LargeInt::parse("7")).is_none());
+        assert!(is_perfect_power(&LargeInt::parse("1")).is_none());
+        assert!(is_perfect_power(&LargeInt::parse("-8")).is_none());
+    }
+
+    #[test]
+    fn test_divide_and_modulo_signs() {
+        let cases = [
+            ("7", "3", "2", "1"),
+            ("-7", "3", "-2", "-1"),
+            ("7", "-3", "-2", "1"),
+            ("-7", "-3", "2", "-1"),
+            ("-6", "3", "-2", "0"),
+        ];
+        for (a, b, q, r) in cases {
+            let (a, b) = (LargeInt::parse(a), LargeInt::parse(b));
+            let (quotient, remainder) = divide_and_modulo(&a, &b);
+            assert_eq!(quotient.to_string(), q);
+            assert_eq!(remainder.to_string(), r);
+            assert_eq!(multiply(&quotient, &b).add(&remainder).to_string(), a.to_string());
+        }
+    }
+
+    #[test]
+    fn test_div_euclid() {
+        let cases = [
+            ("-7", "3", "-3", "2"),
+            ("7", "-3", "-2", "1"),
+            ("-7", "-3", "3", "2"),
+            ("7", "3", "2", "1"),
+            ("-6", "3", "-2", "0"),
+        ];
+        for (a, b, q, r) in cases {
+            let (a, b) = (LargeInt::parse(a), LargeInt::parse(b));
+            let (quotient, remainder) = div_euclid(&a, &b);
+            assert_eq!(quotient.to_string(), q);
+            assert_eq!(remainder.to_string(), r);
+            assert_eq!(multiply(&quotient, &b).add(&remainder).to_string(), a.to_string());
+        }
+    }
+
+    #[test]
+    fn test_gcd() {
+        let gcd_str = |a: &str, b: &str| gcd(&LargeInt::parse(a), &LargeInt::parse(b)).to_string();
+        assert_eq!(gcd_str("12", "18"), "6");
+        assert_eq!(gcd_str("-12", "18"), "6");
+        assert_eq!(gcd_str("0", "5"), "5");
+        assert_eq!(gcd_str("0", "0"), "0");
+        assert_eq!(gcd_str("123456789012345678901234567890", "987654321098765432109876543210"), "9000000000900000000090");
+    }
+
+    #[test]
+    fn test_multiply_seq_matches_multiply() {
+        let mut seed = 3;
+        for (a_len, b_len) in [(1, 1), (5, 80), (63, 64), (64, 64), (200, 150), (500, 3)] {
+            let a = LargeInt::parse(&random_digits(&mut seed, a_len));
+            let b = LargeInt::parse(&format!("-{}", random_digits(&mut seed, b_len)));
+            assert_eq!(multiply_seq(&a, &b).to_string(), multiply(&a, &b).to_string());
+        }
+        assert_eq!(multiply_seq(&LargeInt::parse("-12"), &LargeInt::zero()).to_string(), "0");
+        assert_eq!(multiply_seq(&LargeInt::parse("99"), &LargeInt::parse("99")).to_string(), "9801");
+    }
+
+    #[test]
+    fn test_checked_factorial() {
+        assert_eq!(checked_factorial(&LargeInt::zero()).unwrap().to_string(), "1");
+        assert_eq!(checked_factorial(&LargeInt::parse("20")).unwrap().to_string(), "2432902008176640000");
+        assert_eq!(checked_factorial(&LargeInt::parse("-3")).unwrap_err(), CalcError::NegativeFactorial);
+        let huge = LargeInt::parse("18446744073709551616000");
+        assert_eq!(checked_factorial(&huge).unwrap_err(), CalcError::ArgumentTooLarge);
+    }
+
+    #[test]
+    #[should_panic(expected = "Factorial is not defined for negative numbers")]
+    fn test_factorial_rejects_negative() {
+        factorial(&LargeInt::parse("-1"));
+    }
+
+    #[test]
+    fn test_checked_exponentiate() {
+        let two = LargeInt::two();
+        assert_eq!(
+            checked_exponentiate(&two, &LargeInt::parse("-3")).unwrap_err(),
+            CalcError::NegativeExponent
+        );
+        assert_eq!(checked_exponentiate(&two, &LargeInt::zero()).unwrap().to_string(), "1");
+        assert_eq!(checked_exponentiate(&two, &LargeInt::ten()).unwrap().to_string(), "1024");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_exponentiate_negative_panics() {
+        exponentiate(&LargeInt::two(), &LargeInt::parse("-1"));
+    }
+
+    #[test]
+    fn test_gcd_binary_matches_gcd() {
+        let mut seed = 11;
+        for i in 0..20 {
+            let common = LargeInt::parse(&random_digits(&mut seed, 1 + i % 5));
+            let scale = exponentiate(&LargeInt::two(), &LargeInt::small(i as u8));
+            let a = multiply(&multiply(&common, &scale), &LargeInt::parse(&random_digits(&mut seed, 30)));
+            let b = multiply(&common, &LargeInt::parse(&random_digits(&mut seed, 25)));
+            assert_eq!(gcd_binary(&a, &b).to_string(), gcd(&a, &b).to_string());
+        }
+
+        let gcd_str = |a: &str, b: &str| gcd_binary(&LargeInt::parse(a), &LargeInt::parse(b)).to_string();
+        assert_eq!(gcd_str("-48", "180"), "12");
+        assert_eq!(gcd_str("0", "-7"), "7");
+        assert_eq!(gcd_str("0", "0"), "0");
+        assert_eq!(gcd_str("1024", "4096"), "1024");
+    }
+
+    #[test]
+    fn test_exponentiate_limited() {
+        let ten = LargeInt::ten();
+        assert_eq!(
+            exponentiate_limited(&ten, &LargeInt::parse("1000"), 100).unwrap_err(),
+            CalcError::ResultTooLarge
+        );
+        assert_eq!(
+            exponentiate_limited(&ten, &LargeInt::parse("50"), 100).unwrap().to_string(),
+            LargeInt::pow10(50).to_string()
+        );
+
+        // The exact boundary: 10^99 has 100 digits, 10^100 has 101.
+        assert!(exponentiate_limited(&ten, &LargeInt::parse("99"), 100).is_ok());
+        assert!(exponentiate_limited(&ten, &LargeInt::parse("100"), 100).is_err());
+
+        let nine = LargeInt::small(9);
+        let huge = LargeInt::parse("387420489");
+        assert_eq!(exponentiate_limited(&nine, &huge, 1_000_000).unwrap_err(), CalcError::ResultTooLarge);
+        assert_eq!(exponentiate_limited(&LargeInt::one(), &huge, 1).unwrap().to_string(), "1");
+        assert_eq!(
+            exponentiate_limited(&nine, &LargeInt::parse("-1"), 10).unwrap_err(),
+            CalcError::NegativeExponent
+        );
+    }
 }
@@ -0,0 +1,65 @@
+/// Reads REPL input lines.
+/// On an interactive terminal it uses a line editor with arrow-key history;
+/// when input is piped or redirected it falls back to plain stdin reads.
+use rustyline::DefaultEditor;
+use std::io::{self, IsTerminal, Write};
+
+pub struct LineReader {
+    editor: Option<DefaultEditor>,
+}
+
+impl LineReader {
+    /// Creates a reader, enabling the line editor only when stdin is a terminal.
+    pub fn new() -> Self {
+        let editor = if io::stdin().is_terminal() {
+            DefaultEditor::new().ok()
+        } else {
+            None
+        };
+        LineReader { editor }
+    }
+
+    /// Shows the prompt and reads one trimmed line, recording it in the history.
+    /// End of input and interrupts yield an empty line.
+    pub fn read_line(&mut self, prompt: &str) -> String {
+        match &mut self.editor {
+            Some(editor) => {
+                let line = editor.readline(prompt).unwrap_or_default();
+                if let Some(entry) = history_entry(&line) {
+                    let _ = editor.add_history_entry(entry);
+                }
+                line.trim().to_string()
+            }
+            None => {
+                print!("{}", prompt);
+                io::stdout().flush().unwrap();
+                let mut line = String::new();
+                io::stdin().read_line(&mut line).unwrap();
+                line.trim().to_string()
+            }
+        }
+    }
+}
+
+/// Returns the text worth keeping in the history for an input line, if any.
+fn history_entry(line: &str) -> Option<&str> {
+    let entry = line.trim();
+    if entry.is_empty() {
+        None
+    } else {
+        Some(entry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history_entry() {
+        assert_eq!(history_entry("  3/4 \n"), Some("3/4"));
+        assert_eq!(history_entry("1"), Some("1"));
+        assert_eq!(history_entry("   "), None);
+        assert_eq!(history_entry(""), None);
+    }
+}
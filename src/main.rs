@@ -1,11 +1,22 @@
 // main.rs
-mod fractions;
-mod base_conversion;
+mod line_reader;
+mod output_format;
 
-use fractions::Fraction;
-use std::io::{self, Write};
+use hello::base_conversion;
+use hello::Fraction;
+use line_reader::LineReader;
+use output_format::{format_fraction, OutputFormat};
 
 fn main() {
+    let mut format = match parse_format_arg(std::env::args().skip(1)) {
+        Ok(format) => format,
+        Err(err) => {
+            eprintln!("{}", err);
+            std::process::exit(2);
+        }
+    };
+    let mut reader = LineReader::new();
+
     loop {
         println!("Choose an operation:");
         println!("1. Addition");
@@ -15,14 +26,10 @@ fn main() {
         println!("5. Modulo (Fraction form)");
         println!("6. Exponentiation");
         println!("7. Base Conversion (Decimal to Binary and vice versa)");
+        println!("8. Output Format");
         println!("0. Exit");
 
-        print!("Enter your choice: ");
-        io::stdout().flush().unwrap();
-
-        let mut choice = String::new();
-        io::stdin().read_line(&mut choice).unwrap();
-        let choice = choice.trim().parse::<u32>().unwrap_or(0);
+        let choice = reader.read_line("Enter your choice: ").parse::<u32>().unwrap_or(0);
 
         if choice == 0 {
             println!("Exiting...");
@@ -31,14 +38,14 @@ fn main() {
 
         match choice {
             1..=5 => {
-                let (frac1, frac2) = read_two_fractions();
+                let (frac1, frac2) = read_two_fractions(&mut reader);
                 match choice {
-                    1 => println!("Result: {}", frac1 + frac2),
-                    2 => println!("Result: {}", frac1 - frac2),
-                    3 => println!("Result: {}", frac1 * frac2),
+                    1 => println!("Result: {}", format_fraction(&(frac1 + frac2), format)),
+                    2 => println!("Result: {}", format_fraction(&(frac1 - frac2), format)),
+                    3 => println!("Result: {}", format_fraction(&(frac1 * frac2), format)),
                     4 => {
                         if let Some(result) = frac1.checked_div(frac2.clone()) {
-                            println!("Result: {}", result);
+                            println!("Result: {}", format_fraction(&result, format));
                         } else {
                             println!("Division by zero is not allowed.");
                         }
@@ -48,43 +55,63 @@ fn main() {
                 }
             }
             6 => {
-                let (base, exp) = read_base_and_exponent();
-                let result = base.exponentiate(&exp);
-                println!("Result: {}", result);
+                let (base, exp) = read_base_and_exponent(&mut reader);
+                let result = base.exponentiate(exp);
+                println!("Result: {}", format_fraction(&result, format));
             }
             7 => {
-                base_conversion::run_repl();
+                base_conversion::run_repl(&mut |prompt| reader.read_line(prompt));
+            }
+            8 => {
+                let input = reader.read_line("Enter a format (dec, hex, grouped, sci, mixed, improper): ");
+                match input.parse::<OutputFormat>() {
+                    Ok(new_format) => format = new_format,
+                    Err(err) => println!("{}", err),
+                }
             }
             _ => println!("Invalid choice. Please try again."),
         }
     }
 }
 
-fn read_two_fractions() -> (Fraction, Fraction) {
-    println!("Enter first fraction (e.g., 3/4):");
-    let frac1 = read_fraction();
+/// Reads the output format from `--format <style>` or `--format=<style>`.
+/// Defaults to mixed numbers when the flag is absent.
+fn parse_format_arg(mut args: impl Iterator<Item = String>) -> Result<OutputFormat, String> {
+    let mut format = OutputFormat::Mixed;
+    while let Some(arg) = args.next() {
+        let value = if arg == "--format" {
+            args.next().ok_or("Missing value for --format")?
+        } else if let Some(value) = arg.strip_prefix("--format=") {
+            value.to_string()
+        } else {
+            return Err(format!("Unknown argument '{}'", arg));
+        };
+        format = value.parse()?;
+    }
+    Ok(format)
+}
 
-    println!("Enter second fraction (e.g., 5/6):");
-    let frac2 = read_fraction();
+fn read_two_fractions(reader: &mut LineReader) -> (Fraction, Fraction) {
+    let frac1 = read_fraction(reader, "Enter first fraction (e.g., 3/4): ");
+    let frac2 = read_fraction(reader, "Enter second fraction (e.g., 5/6): ");
 
     (frac1, frac2)
 }
 
-fn read_fraction() -> Fraction {
-    let mut input = String::new();
-    io::stdin().read_line(&mut input).unwrap();
-    Fraction::from_str(input.trim()).unwrap_or_else(|_| {
-        println!("Invalid fraction format. Defaulting to 0.");
+fn read_fraction(reader: &mut LineReader, prompt: &str) -> Fraction {
+    Fraction::checked_from_str(&reader.read_line(prompt)).unwrap_or_else(|err| {
+        println!("Invalid fraction: {}. Defaulting to 0.", err);
         Fraction::new(0, 1)
     })
 }
 
-fn read_base_and_exponent() -> (Fraction, Fraction) {
-    println!("Enter the base (e.g., 2/3):");
-    let base = read_fraction();
+fn read_base_and_exponent(reader: &mut LineReader) -> (Fraction, i64) {
+    let base = read_fraction(reader, "Enter the base (e.g., 2/3): ");
 
-    println!("Enter the exponent (e.g., -2):");
-    let exp = read_fraction();
+    let exp = reader.read_line("Enter the exponent (e.g., -2): ").parse::<i64>().unwrap_or_else(|_| {
+        println!("Invalid exponent. Defaulting to 1.");
+        1
+    });
 
     (base, exp)
 }
@@ -0,0 +1,178 @@
+/// Montgomery arithmetic for repeated modular exponentiation with a fixed odd
+/// modulus. Values are kept as base-2³² limbs with R = 2^(32 * limbs), so each
+/// reduction is a multiply and a shift instead of a long division.
+use crate::{div_euclid, LargeInt};
+
+pub struct MontgomeryCtx {
+    /// The modulus, least significant limb first.
+    modulus: Vec<u32>,
+    /// -modulus⁻¹ mod 2³², used to clear one limb per reduction step.
+    inverse: u32,
+    /// R² mod modulus, for converting values into Montgomery form.
+    r_squared: Vec<u32>,
+    big_modulus: LargeInt,
+}
+
+impl MontgomeryCtx {
+    /// Prepares a context for the given modulus.
+    /// Panics if the modulus is not an odd positive number.
+    pub fn new(modulus: &LargeInt) -> Self {
+        if modulus.sign == -1 || modulus.digits[0].is_multiple_of(2) {
+            panic!("Montgomery modulus must be odd and positive!");
+        }
+
+        let limbs = modulus.to_binary_limbs();
+        let mut inverse: u32 = 1;
+        // Each Newton step doubles the number of correct low bits.
+        for _ in 0..5 {
+            inverse = inverse.wrapping_mul(2u32.wrapping_sub(limbs[0].wrapping_mul(inverse)));
+        }
+
+        let mut r_squared = vec![0u32; 2 * limbs.len() + 1];
+        r_squared[2 * limbs.len()] = 1;
+        let r_squared = div_euclid(&LargeInt::from_binary_limbs(1, &r_squared), modulus).1;
+
+        MontgomeryCtx {
+            r_squared: pad(r_squared.to_binary_limbs(), limbs.len()),
+            modulus: limbs,
+            inverse: inverse.wrapping_neg(),
+            big_modulus: modulus.clone(),
+        }
+    }
+
+    /// Computes `base^exp mod modulus` with a result in `0..modulus`.
+    /// Negative bases are reduced first. Panics if the exponent is negative.
+    pub fn pow(&self, base: &LargeInt, exp: &LargeInt) -> LargeInt {
+        if exp.sign == -1 {
+            panic!("Exponent must be non-negative!");
+        }
+        if self.big_modulus.is_one() {
+            return LargeInt::zero();
+        }
+
+        let n = self.modulus.len();
+        let base = pad(div_euclid(base, &self.big_modulus).1.to_binary_limbs(), n);
+        let base = self.multiply(&base, &self.r_squared);
+        let mut one = vec![0u32; n];
+        one[0] = 1;
+        let mut result = self.multiply(&one, &self.r_squared);
+
+        for limb in exp.to_binary_limbs().iter().rev() {
+            for bit in (0..32).rev() {
+                result = self.multiply(&result, &result);
+                if limb >> bit & 1 == 1 {
+                    result = self.multiply(&result, &base);
+                }
+            }
+        }
+
+        LargeInt::from_binary_limbs(1, &self.multiply(&result, &one))
+    }
+
+    /// Montgomery product `a * b / R mod modulus` of two reduced values (CIOS method).
+    fn multiply(&self, a: &[u32], b: &[u32]) -> Vec<u32> {
+        let n = self.modulus.len();
+        let mut t = vec![0u32; n + 2];
+
+        for &bi in b {
+            let mut carry = 0u64;
+            for j in 0..n {
+                let sum = t[j] as u64 + a[j] as u64 * bi as u64 + carry;
+                t[j] = sum as u32;
+                carry = sum >> 32;
+            }
+            let sum = t[n] as u64 + carry;
+            t[n] = sum as u32;
+            t[n + 1] = (sum >> 32) as u32;
+
+            // Add a multiple of the modulus that clears the lowest limb, then drop it.
+            let m = t[0].wrapping_mul(self.inverse) as u64;
+            let mut carry = (t[0] as u64 + m * self.modulus[0] as u64) >> 32;
+            for j in 1..n {
+                let sum = t[j] as u64 + m * self.modulus[j] as u64 + carry;
+                t[j - 1] = sum as u32;
+                carry = sum >> 32;
+            }
+            let sum = t[n] as u64 + carry;
+            t[n - 1] = sum as u32;
+            t[n] = t[n + 1] + (sum >> 32) as u32;
+            t[n + 1] = 0;
+        }
+
+        if t[n] != 0 || !less_than(&t[..n], &self.modulus) {
+            let mut borrow = 0i64;
+            for (limb, &m) in t.iter_mut().zip(&self.modulus) {
+                let diff = *limb as i64 - m as i64 - borrow;
+                *limb = diff as u32;
+                borrow = (diff < 0) as i64;
+            }
+        }
+        t.truncate(n);
+        t
+    }
+}
+
+/// Extends limbs with zeros to exactly `len` entries.
+fn pad(mut limbs: Vec<u32>, len: usize) -> Vec<u32> {
+    limbs.resize(len, 0);
+    limbs
+}
+
+/// Compares two equal-length limb slices.
+fn less_than(a: &[u32], b: &[u32]) -> bool {
+    a.iter().rev().cmp(b.iter().rev()) == std::cmp::Ordering::Less
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modpow;
+
+    #[test]
+    fn test_pow_matches_modpow() {
+        let moduli = [
+            "3",
+            "7",
+            "4294967291",
+            "4294967297",
+            "18446744073709551557",
+            "340282366920938463463374607431768211507",
+            "123456789012345678901234567890123456789012345678901",
+        ];
+        let bases = ["0", "1", "2", "-5", "65537", "98765432109876543210987654321"];
+        let exps = ["0", "1", "2", "65537", "1000000007", "123456789123456789"];
+
+        for m in moduli {
+            let modulus = LargeInt::parse(m);
+            let ctx = MontgomeryCtx::new(&modulus);
+            for b in bases {
+                for e in exps {
+                    let (base, exp) = (LargeInt::parse(b), LargeInt::parse(e));
+                    assert_eq!(
+                        ctx.pow(&base, &exp).to_string(),
+                        modpow(&base, &exp, &modulus).to_string(),
+                        "{}^{} mod {}",
+                        b,
+                        e,
+                        m
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_pow_small_cases() {
+        let ctx = MontgomeryCtx::new(&LargeInt::parse("13"));
+        assert_eq!(ctx.pow(&LargeInt::parse("2"), &LargeInt::parse("12")).to_string(), "1");
+        assert_eq!(ctx.pow(&LargeInt::parse("3"), &LargeInt::parse("5")).to_string(), "9");
+        let trivial = MontgomeryCtx::new(&LargeInt::one());
+        assert_eq!(trivial.pow(&LargeInt::parse("5"), &LargeInt::parse("3")).to_string(), "0");
+    }
+
+    #[test]
+    #[should_panic(expected = "Montgomery modulus must be odd and positive!")]
+    fn test_even_modulus() {
+        MontgomeryCtx::new(&LargeInt::parse("10"));
+    }
+}
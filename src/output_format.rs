@@ -0,0 +1,86 @@
+/// Output styles for REPL results, chosen with `--format` or from the menu.
+use hello::{Fraction, LargeInt};
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Plain decimal `num/den`, or just the integer.
+    Dec,
+    /// Lowercase hexadecimal parts.
+    Hex,
+    /// Decimal parts with `,` between groups of three digits.
+    Grouped,
+    /// Scientific notation parts, e.g. `1.5e3`.
+    Sci,
+    /// Mixed numbers such as `2 1/3` (the default).
+    Mixed,
+    /// Improper fractions such as `7/3`.
+    Improper,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.trim() {
+            "dec" => Ok(OutputFormat::Dec),
+            "hex" => Ok(OutputFormat::Hex),
+            "grouped" => Ok(OutputFormat::Grouped),
+            "sci" => Ok(OutputFormat::Sci),
+            "mixed" => Ok(OutputFormat::Mixed),
+            "improper" => Ok(OutputFormat::Improper),
+            other => Err(format!(
+                "Unknown format '{}'. Expected dec, hex, grouped, sci, mixed or improper",
+                other
+            )),
+        }
+    }
+}
+
+/// Renders a fraction result in the chosen style.
+/// The integer styles apply to the numerator and denominator separately,
+/// and whole numbers are shown without a denominator.
+pub fn format_fraction(value: &Fraction, format: OutputFormat) -> String {
+    let render = |n: i64| {
+        let n = LargeInt::from(n);
+        match format {
+            OutputFormat::Hex => n.to_str_radix(16),
+            OutputFormat::Grouped => n.to_grouped_string(),
+            OutputFormat::Sci => n.to_scientific_string(),
+            _ => n.to_string(),
+        }
+    };
+
+    match format {
+        OutputFormat::Mixed => value.to_string(),
+        OutputFormat::Improper => value.display_improper().to_string(),
+        _ if value.denominator == 1 => render(value.numerator),
+        _ => format!("{}/{}", render(value.numerator), render(value.denominator)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!("hex".parse::<OutputFormat>(), Ok(OutputFormat::Hex));
+        assert_eq!(" improper ".parse::<OutputFormat>(), Ok(OutputFormat::Improper));
+        assert!("octal".parse::<OutputFormat>().is_err());
+    }
+
+    #[test]
+    fn test_format_fraction() {
+        let whole = Fraction::new(1234567, 1);
+        let part = Fraction::new(7, 3);
+        assert_eq!(format_fraction(&whole, OutputFormat::Dec), "1234567");
+        assert_eq!(format_fraction(&part, OutputFormat::Dec), "7/3");
+        assert_eq!(format_fraction(&Fraction::new(-255, 1), OutputFormat::Hex), "-ff");
+        assert_eq!(format_fraction(&Fraction::new(255, 16), OutputFormat::Hex), "ff/10");
+        assert_eq!(format_fraction(&whole, OutputFormat::Grouped), "1,234,567");
+        assert_eq!(format_fraction(&Fraction::new(1500, 1), OutputFormat::Sci), "1.5e3");
+        assert_eq!(format_fraction(&part, OutputFormat::Mixed), "2 1/3");
+        assert_eq!(format_fraction(&part, OutputFormat::Improper), "7/3");
+    }
+}
@@ -0,0 +1,27 @@
+//! Commonly used items, importable in one line.
+//!
+//! ```
+//! use hello::prelude::*;
+//!
+//! let a = LargeInt::parse("123456789");
+//! let b = LargeInt::parse("1000");
+//! assert_eq!(multiply(&a, &b).to_string(), "123456789000");
+//! let (q, r) = divide_and_modulo(&a, &b);
+//! assert_eq!((q.to_string(), r.to_string()), ("123456".to_string(), "789".to_string()));
+//! assert_eq!(exponentiate(&b, &LargeInt::two()).to_string(), "1000000");
+//! assert_eq!(factorial(&LargeInt::parse("10")).to_string(), "3628800");
+//!
+//! let half = Fraction::new(1, 2);
+//! assert_eq!((half.clone() * half).to_string(), "1/4");
+//! assert_eq!(convert_base("255", 10, 16).unwrap(), "ff");
+//! assert_eq!(to_decimal("1010", 2).unwrap(), 10);
+//! assert_eq!(to_decimal_signed("-ff", 16).unwrap(), -255);
+//! assert_eq!(decimal_to_base_grouped(0xa6, 2, 4, '_'), "1010_0110");
+//! assert!(verify_round_trip("dead", 16, 7));
+//! ```
+pub use crate::base_conversion::{
+    convert_base, decimal_to_base_grouped, to_decimal, to_decimal_signed, to_large_int,
+    verify_round_trip,
+};
+pub use crate::fractions::Fraction;
+pub use crate::{divide_and_modulo, exponentiate, factorial, multiply, LargeInt};
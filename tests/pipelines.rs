@@ -0,0 +1,41 @@
+//! End-to-end checks that chain several modules together, catching
+//! regressions the per-module unit tests cannot see.
+use hello::{divide_and_modulo, exponentiate, multiply, trial_division};
+use hello::{convert_base, Fraction, LargeInt};
+
+#[test]
+fn test_multiply_factor_and_convert_bases() {
+    // 2^20 * 3^7 and 5^9 * 7^4 * 11, so the product factors completely.
+    let a = LargeInt::parse("2293235712");
+    let b = LargeInt::parse("51583984375");
+    let product = multiply(&a, &b);
+    assert_eq!(product.to_string(), "118294235136000000000");
+
+    let (factors, rest) = trial_division(&product, 100);
+    assert_eq!(factors, vec![(2, 20), (3, 7), (5, 9), (7, 4), (11, 1)]);
+    assert_eq!(rest.to_string(), "1");
+    let rebuilt = factors.iter().fold(LargeInt::one(), |acc, &(p, k)| {
+        multiply(&acc, &exponentiate(&LargeInt::from(p as i64), &LargeInt::from(k as i64)))
+    });
+    assert_eq!(rebuilt.to_string(), product.to_string());
+
+    let hex = product.to_str_radix(16);
+    assert_eq!(LargeInt::from_str_radix(&hex, 16).unwrap().to_string(), product.to_string());
+    assert_eq!(convert_base(&hex, 16, 10).unwrap(), product.to_string());
+    assert_eq!(convert_base(&product.to_string(), 10, 16).unwrap(), hex);
+
+    let (quotient, remainder) = divide_and_modulo(&product, &a);
+    assert_eq!(quotient.to_string(), b.to_string());
+    assert!(remainder.is_zero());
+}
+
+#[test]
+fn test_decimal_fraction_power_to_fixed_point() {
+    let price = Fraction::from_decimal_str("1.05").unwrap();
+    assert_eq!(price, Fraction::new(21, 20));
+
+    let compounded = price.exponentiate(3);
+    assert_eq!(compounded, Fraction::new(9261, 8000));
+    assert_eq!(compounded.to_decimal_string(4), "1.1576");
+    assert_eq!(compounded.exponentiate(-1).to_decimal_string(6), "0.863838");
+}